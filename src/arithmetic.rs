@@ -0,0 +1,563 @@
+//! The theory of integer constants and linear arithmetic.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::{dfs, Function, Normalizable, Oracle, Premise, Term, Theory, Unfolding};
+
+/// A literal type whose values can denote integer constants, see [`Arithmetic`].
+pub trait IntegerLiteral: Sized {
+    /// Returns the integer denoted by the literal, or `None` if it does not denote an integer.
+    fn to_integer(&self) -> Option<i64>;
+
+    /// Returns the literal denoting the integer.
+    fn from_integer(integer: i64) -> Self;
+}
+
+impl IntegerLiteral for String {
+    fn to_integer(&self) -> Option<i64> {
+        self.parse().ok()
+    }
+
+    fn from_integer(integer: i64) -> Self {
+        integer.to_string()
+    }
+}
+
+/// The symbols interpreted by the theory of linear arithmetic, see
+/// [`equals_modulo_arithmetic`].
+///
+/// Literals denoting integers, see [`IntegerLiteral`], are integer constants. The addition
+/// symbol is applied to any number of arguments, and the multiplication symbol is interpreted
+/// as long as at most one of its arguments is not a constant. Every other term, including a
+/// product of several non-constant terms, is an uninterpreted atom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Arithmetic<Literal: Ord + Eq + Hash + Clone + Debug> {
+    /// The symbol of addition.
+    pub addition: Literal,
+
+    /// The symbol of multiplication.
+    pub multiplication: Literal,
+}
+
+/// A sum of atoms with integer coefficients plus a constant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LinearForm<Literal: Ord + Eq + Hash + Clone + Debug> {
+    coefficients: BTreeMap<Term<Literal>, i64>,
+    constant: i64,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> LinearForm<Literal> {
+    const fn constant(constant: i64) -> Self {
+        Self {
+            coefficients: BTreeMap::new(),
+            constant,
+        }
+    }
+
+    fn atom(atom: Term<Literal>) -> Self {
+        Self {
+            coefficients: std::iter::once((atom, 1)).collect(),
+            constant: 0,
+        }
+    }
+
+    /// Returns `self + scale * other`, or `None` if a coefficient overflows.
+    fn add(&self, other: &Self, scale: i64) -> Option<Self> {
+        let mut sum = self.clone();
+
+        for (atom, coefficient) in &other.coefficients {
+            let current = sum.coefficients.get(atom).copied().unwrap_or(0);
+            let coefficient = current.checked_add(coefficient.checked_mul(scale)?)?;
+
+            if coefficient == 0 {
+                sum.coefficients.remove(atom);
+            } else {
+                sum.coefficients.insert(atom.clone(), coefficient);
+            }
+        }
+        sum.constant = sum
+            .constant
+            .checked_add(other.constant.checked_mul(scale)?)?;
+
+        Some(sum)
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + IntegerLiteral> Arithmetic<Literal> {
+    /// Creates the theory with the given symbols of addition and multiplication.
+    pub const fn new(addition: Literal, multiplication: Literal) -> Self {
+        Self {
+            addition,
+            multiplication,
+        }
+    }
+
+    /// Rewrites a term into its canonical form.
+    ///
+    /// Arithmetic terms are rewritten into a sum of atoms sorted by [`Ord`], each multiplied by
+    /// its coefficient unless it is one, followed by the constant unless it is zero. The
+    /// arguments of atoms are rewritten as well, so that for example `Array(T, add(2, 2))`
+    /// becomes `Array(T, 4)`.
+    #[must_use]
+    pub fn canonicalize(&self, term: &Term<Literal>) -> Term<Literal> {
+        self.term(&self.linear(term))
+    }
+
+    fn linear(&self, term: &Term<Literal>) -> LinearForm<Literal> {
+        self.try_linear(term)
+            .unwrap_or_else(|| LinearForm::atom(self.atom(term)))
+    }
+
+    fn try_linear(&self, term: &Term<Literal>) -> Option<LinearForm<Literal>> {
+        match term {
+            Term::Literal(literal) => {
+                if let Some(integer) = literal.to_integer() {
+                    return Some(LinearForm::constant(integer));
+                }
+            }
+            Term::Function(function) if function.symbol == self.addition => {
+                let mut sum = LinearForm::constant(0);
+                for argument in &function.arguments {
+                    sum = sum.add(&self.linear(argument), 1)?;
+                }
+
+                return Some(sum);
+            }
+            Term::Function(function) if function.symbol == self.multiplication => {
+                let mut factor = 1_i64;
+                let mut variable = None;
+
+                for argument in &function.arguments {
+                    let form = self.linear(argument);
+
+                    if form.coefficients.is_empty() {
+                        factor = factor.checked_mul(form.constant)?;
+                    } else if variable.is_none() {
+                        variable = Some(form);
+                    } else {
+                        return None;
+                    }
+                }
+
+                return variable.map_or(Some(LinearForm::constant(factor)), |variable| {
+                    LinearForm::constant(0).add(&variable, factor)
+                });
+            }
+            _ => {}
+        }
+
+        Some(LinearForm::atom(self.atom(term)))
+    }
+
+    /// Rewrites the arguments of an atom into their canonical forms.
+    fn atom(&self, term: &Term<Literal>) -> Term<Literal> {
+        match term {
+            Term::Literal(_) => term.clone(),
+            Term::Function(function) => Term::Function(Function {
+                symbol: function.symbol.clone(),
+                arguments: function
+                    .arguments
+                    .iter()
+                    .map(|argument| self.canonicalize(argument))
+                    .collect(),
+            }),
+            Term::Normalizable(normalizable) => Term::Normalizable(Normalizable {
+                symbol: normalizable.symbol.clone(),
+                arguments: normalizable
+                    .arguments
+                    .iter()
+                    .map(|argument| self.canonicalize(argument))
+                    .collect(),
+            }),
+        }
+    }
+
+    fn term(&self, form: &LinearForm<Literal>) -> Term<Literal> {
+        let mut summands = form
+            .coefficients
+            .iter()
+            .map(|(atom, coefficient)| {
+                if *coefficient == 1 {
+                    atom.clone()
+                } else {
+                    Term::Function(Function {
+                        symbol: self.multiplication.clone(),
+                        arguments: vec![
+                            Term::Literal(Literal::from_integer(*coefficient)),
+                            atom.clone(),
+                        ],
+                    })
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if form.constant != 0 || summands.is_empty() {
+            summands.push(Term::Literal(Literal::from_integer(form.constant)));
+        }
+
+        if summands.len() == 1 {
+            summands.remove(0)
+        } else {
+            Term::Function(Function {
+                symbol: self.addition.clone(),
+                arguments: summands,
+            })
+        }
+    }
+
+    /// Inserts the atoms of the term and of the arguments of its atoms into `atoms`.
+    fn collect_atoms(&self, term: &Term<Literal>, atoms: &mut BTreeSet<Term<Literal>>) {
+        for atom in self.linear(term).coefficients.into_keys() {
+            for argument in atom.arguments().unwrap_or_default() {
+                self.collect_atoms(argument, atoms);
+            }
+            atoms.insert(atom);
+        }
+    }
+}
+
+const fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+/// A rational number in lowest terms with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    const ZERO: Self = Self {
+        numerator: 0,
+        denominator: 1,
+    };
+
+    const ONE: Self = Self {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    fn new(numerator: i128, denominator: i128) -> Option<Self> {
+        let divisor = gcd(numerator, denominator);
+        let sign = if denominator < 0 { -1 } else { 1 };
+
+        Some(Self {
+            numerator: (numerator / divisor).checked_mul(sign)?,
+            denominator: (denominator / divisor).checked_mul(sign)?,
+        })
+    }
+
+    const fn is_zero(self) -> bool {
+        self.numerator == 0
+    }
+
+    fn sub(self, other: Self) -> Option<Self> {
+        Self::new(
+            self.numerator
+                .checked_mul(other.denominator)?
+                .checked_sub(other.numerator.checked_mul(self.denominator)?)?,
+            self.denominator.checked_mul(other.denominator)?,
+        )
+    }
+
+    fn mul(self, other: Self) -> Option<Self> {
+        Self::new(
+            self.numerator.checked_mul(other.numerator)?,
+            self.denominator.checked_mul(other.denominator)?,
+        )
+    }
+
+    fn div(self, other: Self) -> Option<Self> {
+        Self::new(
+            self.numerator.checked_mul(other.denominator)?,
+            self.denominator.checked_mul(other.numerator)?,
+        )
+    }
+}
+
+/// A linear equation `sum + constant = 0` with rational coefficients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Row<Literal: Ord + Eq + Hash + Clone + Debug> {
+    coefficients: BTreeMap<Term<Literal>, Rational>,
+    constant: Rational,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Row<Literal> {
+    fn new(form: &LinearForm<Literal>) -> Self {
+        Self {
+            coefficients: form
+                .coefficients
+                .iter()
+                .map(|(atom, coefficient)| {
+                    (
+                        atom.clone(),
+                        Rational {
+                            numerator: i128::from(*coefficient),
+                            denominator: 1,
+                        },
+                    )
+                })
+                .collect(),
+            constant: Rational {
+                numerator: i128::from(form.constant),
+                denominator: 1,
+            },
+        }
+    }
+
+    /// Multiplies the row by `factor`.
+    fn scale(&mut self, factor: Rational) -> Option<()> {
+        for coefficient in self.coefficients.values_mut() {
+            *coefficient = coefficient.mul(factor)?;
+        }
+        self.constant = self.constant.mul(factor)?;
+
+        Some(())
+    }
+
+    /// Subtracts `scale * other` from the row.
+    fn subtract(&mut self, other: &Self, scale: Rational) -> Option<()> {
+        for (atom, coefficient) in &other.coefficients {
+            let current = self
+                .coefficients
+                .get(atom)
+                .copied()
+                .unwrap_or(Rational::ZERO);
+            let coefficient = current.sub(coefficient.mul(scale)?)?;
+
+            if coefficient.is_zero() {
+                self.coefficients.remove(atom);
+            } else {
+                self.coefficients.insert(atom.clone(), coefficient);
+            }
+        }
+        self.constant = self.constant.sub(other.constant.mul(scale)?)?;
+
+        Some(())
+    }
+}
+
+/// The linear equations of a premise in reduced row echelon form.
+struct Saturation<'a, Literal: Ord + Eq + Hash + Clone + Debug> {
+    arithmetic: &'a Arithmetic<Literal>,
+
+    /// The rows, each of which has its first atom as the pivot with coefficient one, which no
+    /// other row mentions.
+    rows: Vec<Row<Literal>>,
+
+    /// Whether the equations have no solution.
+    inconsistent: bool,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + IntegerLiteral> Saturation<'_, Literal> {
+    /// Eliminates the pivots of all rows from the given row.
+    fn reduce(&self, mut row: Row<Literal>) -> Option<Row<Literal>> {
+        for pivot_row in &self.rows {
+            let (pivot, _) = pivot_row.coefficients.first_key_value()?;
+
+            if let Some(coefficient) = row.coefficients.get(pivot).copied() {
+                row.subtract(pivot_row, coefficient)?;
+            }
+        }
+
+        Some(row)
+    }
+
+    /// Adds an equation, returning `true` if it was not already implied.
+    ///
+    /// Equations whose elimination overflows are dropped.
+    fn insert(&mut self, form: &LinearForm<Literal>) -> bool {
+        self.try_insert(form).unwrap_or(false)
+    }
+
+    fn try_insert(&mut self, form: &LinearForm<Literal>) -> Option<bool> {
+        let mut row = self.reduce(Row::new(form))?;
+
+        let Some((pivot, coefficient)) = row.coefficients.first_key_value() else {
+            self.inconsistent |= !row.constant.is_zero();
+            return Some(false);
+        };
+        let (pivot, inverse) = (pivot.clone(), Rational::ONE.div(*coefficient)?);
+        row.scale(inverse)?;
+
+        // the rows are only replaced once every elimination succeeded
+        let mut rows = self.rows.clone();
+        for existing in &mut rows {
+            if let Some(coefficient) = existing.coefficients.get(&pivot).copied() {
+                existing.subtract(&row, coefficient)?;
+            }
+        }
+        rows.push(row);
+        self.rows = rows;
+
+        Some(true)
+    }
+
+    fn implies(&self, form: &LinearForm<Literal>) -> bool {
+        self.inconsistent
+            || self
+                .reduce(Row::new(form))
+                .is_some_and(|row| row.coefficients.is_empty() && row.constant.is_zero())
+    }
+
+    /// Returns the integer that the atom is implied to be equal to, if any.
+    fn value(&self, atom: &Term<Literal>) -> Option<i64> {
+        let row = self.reduce(Row::new(&LinearForm::atom(atom.clone())))?;
+
+        if !row.coefficients.is_empty() || row.constant.denominator != 1 {
+            return None;
+        }
+
+        i64::try_from(row.constant.numerator.checked_neg()?).ok()
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + IntegerLiteral> Theory<Literal>
+    for Saturation<'_, Literal>
+{
+    fn equals(
+        &self,
+        term1: &Term<Literal>,
+        term2: &Term<Literal>,
+        _: &mut Oracle<'_, Literal>,
+    ) -> bool {
+        self.arithmetic
+            .linear(term1)
+            .add(&self.arithmetic.linear(term2), -1)
+            .is_some_and(|difference| self.implies(&difference))
+    }
+}
+
+/// Rewrites terms into their [canonical form](Arithmetic::canonicalize) and shows terms equal
+/// whose difference is zero.
+///
+/// Unlike [`equals_modulo_arithmetic`], this does not read the equalities of the premise as
+/// linear equations, so it can be combined with other theories in
+/// [`equals_with_theories`](crate::equals_with_theories) at the cost of completeness.
+impl<Literal: Ord + Eq + Hash + Clone + Debug + IntegerLiteral> Theory<Literal>
+    for Arithmetic<Literal>
+{
+    fn normalize(&self, term: &Term<Literal>, _: &Premise<Literal>) -> Option<Term<Literal>> {
+        Some(self.canonicalize(term)).filter(|canonical| canonical != term)
+    }
+
+    fn equals(
+        &self,
+        term1: &Term<Literal>,
+        term2: &Term<Literal>,
+        _: &mut Oracle<'_, Literal>,
+    ) -> bool {
+        self.linear(term1)
+            .add(&self.linear(term2), -1)
+            .is_some_and(|difference| difference == LinearForm::constant(0))
+    }
+}
+
+/// Determines if two terms are equal modulo the theory of linear arithmetic.
+///
+/// The equalities of the premise are read as linear equations over the rationals, in which
+/// every term that is not interpreted by the theory is an atom. The equations are combined with
+/// the uninterpreted reasoning of [`equals`](crate::equals) in the style of Nelson and Oppen:
+/// equalities between atoms that follow from the equations are added to the premise, and
+/// equalities between atoms that follow from the premise are added to the equations, until
+/// neither produces anything new.
+///
+/// Since the equations are solved over the rationals, equalities that only hold over the
+/// integers may not be found. If the equations have no solution, every equality holds.
+#[must_use]
+pub fn equals_modulo_arithmetic<Literal: Ord + Eq + Hash + Clone + Debug + IntegerLiteral>(
+    term1: &Term<Literal>,
+    term2: &Term<Literal>,
+    premise: &Premise<Literal>,
+    arithmetic: &Arithmetic<Literal>,
+) -> bool {
+    let mut saturation = Saturation {
+        arithmetic,
+        rows: Vec::new(),
+        inconsistent: false,
+    };
+    let mut atoms = BTreeSet::new();
+
+    arithmetic.collect_atoms(term1, &mut atoms);
+    arithmetic.collect_atoms(term2, &mut atoms);
+
+    for (lhs, rhs) in premise.sorted_equalities() {
+        arithmetic.collect_atoms(lhs, &mut atoms);
+        arithmetic.collect_atoms(rhs, &mut atoms);
+
+        if let Some(difference) = arithmetic.linear(lhs).add(&arithmetic.linear(rhs), -1) {
+            saturation.insert(&difference);
+        }
+    }
+
+    let atoms = atoms.into_iter().collect::<Vec<_>>();
+    let mut extended = premise.clone();
+    let contains = |premise: &Premise<Literal>, lhs: &Term<Literal>, rhs: &Term<Literal>| {
+        premise
+            .equalities()
+            .get(lhs)
+            .is_some_and(|equalities| equalities.contains(rhs))
+    };
+
+    loop {
+        if saturation.inconsistent {
+            return true;
+        }
+
+        let mut changed = false;
+
+        for atom in &atoms {
+            if let Some(value) = saturation.value(atom) {
+                let value = Term::Literal(Literal::from_integer(value));
+
+                if !contains(&extended, atom, &value) {
+                    extended.insert(atom.clone(), value);
+                    changed = true;
+                }
+            }
+        }
+
+        for (index, lhs) in atoms.iter().enumerate() {
+            for rhs in &atoms[index + 1..] {
+                let difference =
+                    LinearForm::atom(lhs.clone()).add(&LinearForm::atom(rhs.clone()), -1);
+                let Some(difference) = difference else {
+                    continue;
+                };
+
+                if saturation.implies(&difference) {
+                    if !contains(&extended, lhs, rhs) {
+                        extended.insert(lhs.clone(), rhs.clone());
+                        changed = true;
+                    }
+                } else if dfs(
+                    lhs,
+                    rhs,
+                    &extended,
+                    &[&Unfolding, &saturation],
+                    &mut HashSet::new(),
+                ) {
+                    changed |= saturation.insert(&difference);
+                }
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    dfs(
+        term1,
+        term2,
+        &extended,
+        &[&Unfolding, &saturation],
+        &mut HashSet::new(),
+    )
+}
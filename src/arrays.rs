@@ -0,0 +1,233 @@
+//! The theory of arrays, or finite maps, with read-over-write axioms.
+
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::{Conflict, Function, Oracle, Premise, Term, Theory};
+
+/// The theory of arrays over a pair of function symbols `select(m, k)`, reading the value of
+/// key `k` in `m`, and `store(m, k, v)`, writing value `v` to key `k` in `m`.
+///
+/// The theory uses the read-over-write axioms
+///
+/// ``` txt
+/// select(store(m, k, v), k) = v
+/// select(store(m, k, v), j) = select(m, j)    if k != j
+/// ```
+///
+/// where keys that cannot be shown equal or distinct are split into both cases. Keys are only
+/// known to be distinct if they are equal to different [distinct keys](Self::distinct_keys).
+///
+/// Stores are also simplified by the extensionality consequences
+///
+/// ``` txt
+/// store(store(m, k, v), k, w) = store(m, k, w)
+/// store(m, k, select(m, k)) = m
+/// store(store(m, k, v), j, w) = store(store(m, j, w), k, v)   if k != j
+/// ```
+///
+/// where the last one is used to sort writes to syntactically distinct keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Arrays<Literal: Ord + Eq + Hash + Clone + Debug> {
+    /// The symbol of reading from an array.
+    pub select: Literal,
+
+    /// The symbol of writing to an array.
+    pub store: Literal,
+
+    /// The keys that are pairwise distinct, such as the field names of records.
+    ///
+    /// A premise that equates two of these keys contradicts the theory.
+    pub distinct_keys: BTreeSet<Term<Literal>>,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Arrays<Literal> {
+    /// Creates the theory with the given symbols and no distinct keys.
+    #[must_use]
+    pub const fn new(select: Literal, store: Literal) -> Self {
+        Self {
+            select,
+            store,
+            distinct_keys: BTreeSet::new(),
+        }
+    }
+
+    /// Declares the keys to be pairwise distinct.
+    #[must_use]
+    pub fn with_distinct_keys(mut self, keys: impl IntoIterator<Item = Term<Literal>>) -> Self {
+        self.distinct_keys.extend(keys);
+        self
+    }
+
+    fn as_select<'a>(
+        &self,
+        term: &'a Term<Literal>,
+    ) -> Option<(&'a Term<Literal>, &'a Term<Literal>)> {
+        match term {
+            Term::Function(Function { symbol, arguments }) if *symbol == self.select => {
+                match arguments.as_slice() {
+                    [array, key] => Some((array, key)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn as_store<'a>(
+        &self,
+        term: &'a Term<Literal>,
+    ) -> Option<(&'a Term<Literal>, &'a Term<Literal>, &'a Term<Literal>)> {
+        match term {
+            Term::Function(Function { symbol, arguments }) if *symbol == self.store => {
+                match arguments.as_slice() {
+                    [array, key, value] => Some((array, key, value)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn select(&self, array: Term<Literal>, key: Term<Literal>) -> Term<Literal> {
+        Term::Function(Function {
+            symbol: self.select.clone(),
+            arguments: vec![array, key],
+        })
+    }
+
+    fn store(
+        &self,
+        array: Term<Literal>,
+        key: Term<Literal>,
+        value: Term<Literal>,
+    ) -> Term<Literal> {
+        Term::Function(Function {
+            symbol: self.store.clone(),
+            arguments: vec![array, key, value],
+        })
+    }
+
+    fn syntactically_distinct(&self, key1: &Term<Literal>, key2: &Term<Literal>) -> bool {
+        key1 != key2 && self.distinct_keys.contains(key1) && self.distinct_keys.contains(key2)
+    }
+
+    fn distinct(
+        &self,
+        key1: &Term<Literal>,
+        key2: &Term<Literal>,
+        oracle: &mut Oracle<'_, Literal>,
+    ) -> bool {
+        let equal_keys = |key: &Term<Literal>, oracle: &mut Oracle<'_, Literal>| {
+            self.distinct_keys
+                .iter()
+                .filter(|distinct| oracle.equals(key, distinct))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        let candidates = equal_keys(key1, oracle);
+        let others = equal_keys(key2, oracle);
+
+        candidates
+            .iter()
+            .any(|candidate| others.iter().any(|other| candidate != other))
+    }
+
+    /// Determines if `select(store(array, key, value), index)` is equal to `other`.
+    fn equals_read_over_write(
+        &self,
+        (array, key, value): (&Term<Literal>, &Term<Literal>, &Term<Literal>),
+        index: &Term<Literal>,
+        other: &Term<Literal>,
+        oracle: &mut Oracle<'_, Literal>,
+    ) -> bool {
+        let read = self.select(array.clone(), index.clone());
+
+        if oracle.equals(key, index) {
+            return oracle.equals(value, other);
+        }
+        if self.distinct(key, index, oracle) {
+            return oracle.equals(&read, other);
+        }
+
+        // the keys may or may not be equal, so the read must be equal in both cases
+        oracle.equals_assuming(value, other, &[(key.clone(), index.clone())])
+            && oracle.equals(&read, other)
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Theory<Literal> for Arrays<Literal> {
+    fn normalize(&self, term: &Term<Literal>, _: &Premise<Literal>) -> Option<Term<Literal>> {
+        if let Some((array, index)) = self.as_select(term) {
+            let (array, key, value) = self.as_store(array)?;
+
+            if key == index {
+                return Some(value.clone());
+            }
+            if self.syntactically_distinct(key, index) {
+                return Some(self.select(array.clone(), index.clone()));
+            }
+
+            return None;
+        }
+
+        let (array, key, value) = self.as_store(term)?;
+
+        if let Some((inner, inner_key)) = self.as_select(value) {
+            if inner == array && inner_key == key {
+                return Some(array.clone());
+            }
+        }
+
+        let (inner, inner_key, inner_value) = self.as_store(array)?;
+
+        if inner_key == key {
+            return Some(self.store(inner.clone(), key.clone(), value.clone()));
+        }
+        if self.syntactically_distinct(inner_key, key) && key < inner_key {
+            return Some(self.store(
+                self.store(inner.clone(), key.clone(), value.clone()),
+                inner_key.clone(),
+                inner_value.clone(),
+            ));
+        }
+
+        None
+    }
+
+    fn equals(
+        &self,
+        term1: &Term<Literal>,
+        term2: &Term<Literal>,
+        oracle: &mut Oracle<'_, Literal>,
+    ) -> bool {
+        for (term, other) in [(term1, term2), (term2, term1)] {
+            let Some((array, index)) = self.as_select(term) else {
+                continue;
+            };
+            let Some(store) = self.as_store(array) else {
+                continue;
+            };
+
+            if self.equals_read_over_write(store, index, other, oracle) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn check(&self, oracle: &mut Oracle<'_, Literal>) -> Result<(), Conflict> {
+        for (index, key1) in self.distinct_keys.iter().enumerate() {
+            for key2 in self.distinct_keys.iter().skip(index + 1) {
+                if oracle.equals(key1, key2) {
+                    return Err(Conflict);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
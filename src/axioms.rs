@@ -0,0 +1,149 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::{Function, Normalizable, Premise, Term};
+
+/// The equational axioms that hold for a function symbol, see [`Premise::insert_axioms`].
+///
+/// For example, a union operator with the empty set as its identity satisfies all four axioms:
+///
+/// ``` txt
+/// Union(a, b) = Union(b, a)                       // commutative
+/// Union(a, Union(b, c)) = Union(Union(a, b), c)   // associative
+/// Union(a, a) = a                                 // idempotent
+/// Union(a, Empty) = a                             // identity
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SymbolAxioms<Literal: Ord + Eq + Hash + Clone + Debug> {
+    /// Whether the order of the arguments is irrelevant.
+    pub commutative: bool,
+
+    /// Whether nested applications of the symbol can be flattened into a single application
+    /// with any number of arguments.
+    pub associative: bool,
+
+    /// Whether repeated arguments can be removed.
+    pub idempotent: bool,
+
+    /// The argument that can be removed from any application of the symbol.
+    ///
+    /// An application without any arguments left is equal to the identity itself.
+    pub identity: Option<Term<Literal>>,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Default for SymbolAxioms<Literal> {
+    fn default() -> Self {
+        Self {
+            commutative: false,
+            associative: false,
+            idempotent: false,
+            identity: None,
+        }
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> SymbolAxioms<Literal> {
+    /// Creates the axioms of a commutative symbol.
+    #[must_use]
+    pub fn commutative() -> Self {
+        Self {
+            commutative: true,
+            ..Self::default()
+        }
+    }
+
+    /// Creates the axioms of an associative symbol.
+    #[must_use]
+    pub fn associative() -> Self {
+        Self {
+            associative: true,
+            ..Self::default()
+        }
+    }
+
+    /// Creates the axioms of an associative and commutative symbol.
+    #[must_use]
+    pub fn ac() -> Self {
+        Self {
+            commutative: true,
+            associative: true,
+            ..Self::default()
+        }
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Premise<Literal> {
+    /// Rewrites a term into the canonical form given by the axioms of its function symbols.
+    ///
+    /// Applications of an associative symbol are flattened, identity arguments are removed,
+    /// the arguments of a commutative symbol are sorted, and repeated arguments of an idempotent
+    /// symbol are removed. Non-associative symbols only use the identity and idempotence axioms
+    /// when applied to two arguments. An application left with a single argument is replaced by
+    /// that argument, and one left without arguments by the identity. The same holds for an
+    /// application of an associative symbol, or of a symbol with an identity, that is written
+    /// with a single argument or none.
+    ///
+    /// Terms that are syntactically equal after canonicalization are equal modulo the axioms.
+    #[must_use]
+    pub fn canonicalize(&self, term: &Term<Literal>) -> Term<Literal> {
+        match term {
+            Term::Literal(_) => term.clone(),
+            Term::Normalizable(normalizable) => Term::Normalizable(Normalizable {
+                symbol: normalizable.symbol.clone(),
+                arguments: normalizable
+                    .arguments
+                    .iter()
+                    .map(|argument| self.canonicalize(argument))
+                    .collect(),
+            }),
+            Term::Function(function) => {
+                let symbol = function.symbol.clone();
+                let mut arguments = function
+                    .arguments
+                    .iter()
+                    .map(|argument| self.canonicalize(argument))
+                    .collect::<Vec<_>>();
+
+                let Some(axioms) = self.get_axioms(&symbol) else {
+                    return Term::Function(Function { symbol, arguments });
+                };
+
+                if axioms.associative {
+                    arguments = arguments
+                        .into_iter()
+                        .flat_map(|argument| match argument {
+                            Term::Function(inner) if inner.symbol == symbol => inner.arguments,
+                            argument => vec![argument],
+                        })
+                        .collect();
+                }
+
+                let arity = arguments.len();
+                let binary = axioms.associative || arity == 2;
+
+                if let Some(identity) = axioms.identity.as_ref().filter(|_| binary) {
+                    arguments.retain(|argument| argument != identity);
+                }
+                if axioms.commutative {
+                    arguments.sort();
+                }
+                if axioms.idempotent && binary {
+                    arguments.dedup();
+                }
+
+                // `U(a, e) = a` for an identity `e` extends to `U(a) = a`, and associativity
+                // makes a single argument a flattened application of its own
+                if arguments.len() < arity || axioms.associative || axioms.identity.is_some() {
+                    match (arguments.len(), &axioms.identity) {
+                        (0, Some(identity)) => return identity.clone(),
+                        (1, _) => return arguments.remove(0),
+                        _ => {}
+                    }
+                }
+
+                Term::Function(Function { symbol, arguments })
+            }
+        }
+    }
+}
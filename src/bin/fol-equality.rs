@@ -0,0 +1,147 @@
+//! Answers equality queries against a premise file.
+//!
+//! ``` txt
+//! fol-equality [--explain] <PREMISE> [QUERIES]
+//! ```
+//!
+//! The premise file is written in the syntax of [`parse_premise`]. Each line of the query file,
+//! or of the standard input if no query file is given, is a query of the form `? t1 = t2`, to
+//! which the tool answers `yes` or `no` along with the time it took. Empty lines and lines
+//! starting with `//` are skipped. If the standard input is a terminal, a prompt is shown
+//! before each query.
+//!
+//! With `--explain`, a `yes` answer is followed by a minimal set of equalities of the premise
+//! that are enough to derive the query.
+
+use std::io::{BufRead, IsTerminal, Write};
+use std::process::ExitCode;
+use std::time::Instant;
+
+use fol_equality::{equals, parse_premise, parse_term, Term};
+
+const USAGE: &str = "usage: fol-equality [--explain] <PREMISE> [QUERIES]";
+
+struct Options {
+    explain: bool,
+    premise: String,
+    queries: Option<String>,
+}
+
+fn parse_options() -> Result<Options, String> {
+    let mut explain = false;
+    let mut paths = Vec::new();
+
+    for argument in std::env::args().skip(1) {
+        match argument.as_str() {
+            "--explain" => explain = true,
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            option if option.starts_with('-') => {
+                return Err(format!("unknown option `{option}`\n{USAGE}"));
+            }
+            _ => paths.push(argument),
+        }
+    }
+
+    let mut paths = paths.into_iter();
+    let (Some(premise), queries, None) = (paths.next(), paths.next(), paths.next()) else {
+        return Err(USAGE.to_string());
+    };
+
+    Ok(Options {
+        explain,
+        premise,
+        queries,
+    })
+}
+
+fn parse_query(line: &str) -> Result<(Term<String>, Term<String>), String> {
+    let query = line
+        .strip_prefix('?')
+        .ok_or("a query must start with `?`")?;
+    let (lhs, rhs) = query
+        .split_once('=')
+        .ok_or("a query must be of the form `? t1 = t2`")?;
+
+    let lhs = parse_term(lhs).map_err(|error| format!("left-hand side: {error}"))?;
+    let rhs = parse_term(rhs).map_err(|error| format!("right-hand side: {error}"))?;
+
+    Ok((lhs, rhs))
+}
+
+fn run(options: &Options) -> Result<bool, String> {
+    let source = std::fs::read_to_string(&options.premise)
+        .map_err(|error| format!("{}: {error}", options.premise))?;
+    let premise = parse_premise(&source).map_err(|error| format!("{}:{error}", options.premise))?;
+
+    let (input, interactive): (Box<dyn BufRead>, bool) = match &options.queries {
+        Some(path) => {
+            let file = std::fs::File::open(path).map_err(|error| format!("{path}: {error}"))?;
+            (Box::new(std::io::BufReader::new(file)), false)
+        }
+        None => (
+            Box::new(std::io::stdin().lock()),
+            std::io::stdin().is_terminal(),
+        ),
+    };
+
+    let mut stdout = std::io::stdout().lock();
+    let mut success = true;
+    let mut lines = input.lines().enumerate();
+
+    loop {
+        if interactive {
+            let _ = write!(stdout, "> ");
+            let _ = stdout.flush();
+        }
+
+        let Some((number, line)) = lines.next() else {
+            break;
+        };
+        let line = line.map_err(|error| error.to_string())?;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+
+        let (lhs, rhs) = match parse_query(line) {
+            Ok(query) => query,
+            Err(error) => {
+                eprintln!("{}: {error}", number + 1);
+                success = false;
+                continue;
+            }
+        };
+
+        let start = Instant::now();
+        let result = equals(&lhs, &rhs, &premise);
+        let elapsed = start.elapsed();
+
+        let _ = writeln!(
+            stdout,
+            "{} ({elapsed:.3?})",
+            if result { "yes" } else { "no" }
+        );
+
+        if result && options.explain {
+            for (lhs, rhs) in premise.explain(&lhs, &rhs).unwrap_or_default() {
+                let _ = writeln!(stdout, "  because {lhs} = {rhs}");
+            }
+        }
+    }
+
+    Ok(success)
+}
+
+fn main() -> ExitCode {
+    let result = parse_options().and_then(|options| run(&options));
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::from(2)
+        }
+    }
+}
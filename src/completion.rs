@@ -0,0 +1,293 @@
+//! Ground Knuth–Bendix completion of premises into convergent rewrite systems.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+use crate::premise::Unfolder;
+use crate::{Function, Normalizable, Normalization, Premise, Term};
+
+/// A total ordering of ground terms that orients the equalities of a premise into rewrite
+/// rules, see [`Premise::complete_with`].
+///
+/// The ordering must be a simplification ordering: it must be total on distinct terms, a term
+/// must be greater than its subterms, and replacing a subterm with a smaller term must make the
+/// term smaller. This guarantees that completion and rewriting terminate.
+pub trait TermOrdering<Literal: Ord + Eq + Hash + Clone + Debug> {
+    /// Compares two terms, where the greater term is rewritten into the smaller one.
+    fn compare(&self, term1: &Term<Literal>, term2: &Term<Literal>) -> Ordering;
+}
+
+/// The Knuth–Bendix ordering where every symbol has weight one.
+///
+/// A term with more symbols is greater. Terms with the same number of symbols are compared by
+/// their head symbols, where literals come before functions and functions before normalizables,
+/// and then by their arguments from left to right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct KnuthBendixOrdering;
+
+impl KnuthBendixOrdering {
+    fn weight<Literal: Ord + Eq + Hash + Clone + Debug>(term: &Term<Literal>) -> usize {
+        1 + term
+            .arguments()
+            .unwrap_or_default()
+            .iter()
+            .map(Self::weight)
+            .sum::<usize>()
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> TermOrdering<Literal> for KnuthBendixOrdering {
+    fn compare(&self, term1: &Term<Literal>, term2: &Term<Literal>) -> Ordering {
+        Self::weight(term1)
+            .cmp(&Self::weight(term2))
+            .then_with(|| term1.head().cmp(&term2.head()))
+            .then_with(|| {
+                let arguments1 = term1.arguments().unwrap_or_default();
+                let arguments2 = term2.arguments().unwrap_or_default();
+
+                arguments1
+                    .iter()
+                    .zip(arguments2)
+                    .map(|(argument1, argument2)| self.compare(argument1, argument2))
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            })
+    }
+}
+
+/// An error produced when completing a premise, see [`Premise::complete_with`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompletionError<Literal: Ord + Eq + Hash + Clone + Debug> {
+    /// The normalization of the symbol refers back to itself, so it cannot be unfolded
+    /// completely.
+    RecursiveNormalization(Literal),
+
+    /// The function symbol has axioms, which cannot be oriented into ground rules.
+    Axioms(Literal),
+
+    /// The ordering considers two distinct terms equal.
+    Unorientable(Term<Literal>, Term<Literal>),
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + Display> Display for CompletionError<Literal> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RecursiveNormalization(symbol) => {
+                write!(f, "normalization `{symbol}` is recursive")
+            }
+            Self::Axioms(symbol) => {
+                write!(f, "symbol `{symbol}` has axioms")
+            }
+            Self::Unorientable(lhs, rhs) => {
+                write!(f, "the ordering cannot orient `{lhs} = {rhs}`")
+            }
+        }
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + Display> std::error::Error
+    for CompletionError<Literal>
+{
+}
+
+/// A convergent set of ground rewrite rules, produced by completing a premise, see
+/// [`Premise::complete`].
+///
+/// Two terms are equal under the premise exactly when they have the same
+/// [normal form](Self::normal_form).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RewriteSystem<Literal: Ord + Eq + Hash + Clone + Debug> {
+    rules: HashMap<Term<Literal>, Term<Literal>>,
+    normalizations: HashMap<Literal, Normalization<Literal>>,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> RewriteSystem<Literal> {
+    /// Returns the ground rules, from each left-hand side to its right-hand side.
+    ///
+    /// The rules are reduced: no left-hand side contains another left-hand side, and every
+    /// right-hand side is in normal form.
+    #[must_use]
+    pub const fn rules(&self) -> &HashMap<Term<Literal>, Term<Literal>> {
+        &self.rules
+    }
+
+    /// Returns the normalizations of the premise, which are applied as rules that rewrite a
+    /// normalizable term into its unfolding before any ground rule.
+    #[must_use]
+    pub const fn normalizations(&self) -> &HashMap<Literal, Normalization<Literal>> {
+        &self.normalizations
+    }
+
+    /// Rewrites a term with the ground rules, innermost first, until no rule applies.
+    fn rewrite(&self, term: &Term<Literal>) -> Term<Literal> {
+        let term = match term {
+            Term::Literal(_) => term.clone(),
+            Term::Function(Function { symbol, arguments }) => Term::Function(Function {
+                symbol: symbol.clone(),
+                arguments: arguments
+                    .iter()
+                    .map(|argument| self.rewrite(argument))
+                    .collect(),
+            }),
+            Term::Normalizable(Normalizable { symbol, arguments }) => {
+                Term::Normalizable(Normalizable {
+                    symbol: symbol.clone(),
+                    arguments: arguments
+                        .iter()
+                        .map(|argument| self.rewrite(argument))
+                        .collect(),
+                })
+            }
+        };
+
+        self.rules
+            .get(&term)
+            .map_or(term, |rewritten| self.rewrite(rewritten))
+    }
+
+    /// Returns the normal form of a term, after unfolding every normalization and applying
+    /// the ground rules until none applies.
+    #[must_use]
+    pub fn normal_form(&self, term: &Term<Literal>) -> Term<Literal> {
+        self.rewrite(&term.clone().fold(&mut Unfolder {
+            normalizations: &self.normalizations,
+        }))
+    }
+
+    /// Determines if two terms are equal by comparing their normal forms.
+    #[must_use]
+    pub fn equals(&self, term1: &Term<Literal>, term2: &Term<Literal>) -> bool {
+        self.normal_form(term1) == self.normal_form(term2)
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + Display> Display for RewriteSystem<Literal> {
+    /// Formats the rules as `lhs -> rhs;`, one per line, sorted with the normalizations first.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut normalizations = self.normalizations.iter().collect::<Vec<_>>();
+        normalizations.sort_by_key(|(symbol, _)| *symbol);
+
+        for (symbol, normalization) in normalizations {
+            let normalizable = Normalizable {
+                symbol: symbol.clone(),
+                arguments: normalization
+                    .parameters
+                    .iter()
+                    .cloned()
+                    .map(Term::Literal)
+                    .collect(),
+            };
+            writeln!(f, "{normalizable} -> {};", normalization.equivalence)?;
+        }
+
+        let mut rules = self.rules.iter().collect::<Vec<_>>();
+        rules.sort();
+
+        for (lhs, rhs) in rules {
+            writeln!(f, "{lhs} -> {rhs};")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn contains<Literal: Ord + Eq + Hash + Clone + Debug>(
+    term: &Term<Literal>,
+    subterm: &Term<Literal>,
+) -> bool {
+    term == subterm
+        || term
+            .arguments()
+            .unwrap_or_default()
+            .iter()
+            .any(|argument| contains(argument, subterm))
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Premise<Literal> {
+    /// Completes the premise into a convergent rewrite system using the
+    /// [`KnuthBendixOrdering`], see [`Premise::complete_with`].
+    ///
+    /// # Errors
+    ///
+    /// See [`Premise::complete_with`].
+    pub fn complete(&self) -> Result<RewriteSystem<Literal>, CompletionError<Literal>> {
+        self.complete_with(&KnuthBendixOrdering)
+    }
+
+    /// Completes the premise into a convergent rewrite system, orienting every equality from
+    /// the greater term to the smaller one under the given ordering.
+    ///
+    /// Normalizations are unfolded in every equality before it is oriented. Whenever a new rule
+    /// is added, the rules whose left-hand side contains the new left-hand side are turned back
+    /// into equalities, and the right-hand sides of the others are rewritten with the new rule.
+    /// Since the rules are ground, this leaves no critical pairs and always terminates.
+    ///
+    /// # Errors
+    ///
+    /// - [`CompletionError::RecursiveNormalization`] if a normalization refers back to itself.
+    /// - [`CompletionError::Axioms`] if the premise declares axioms for a symbol.
+    /// - [`CompletionError::Unorientable`] if the ordering considers two distinct terms equal.
+    pub fn complete_with(
+        &self,
+        ordering: &dyn TermOrdering<Literal>,
+    ) -> Result<RewriteSystem<Literal>, CompletionError<Literal>> {
+        if let Some(symbol) = self.recursive_normalization() {
+            return Err(CompletionError::RecursiveNormalization(symbol.clone()));
+        }
+        if let Some(symbol) = self.axioms().keys().min() {
+            return Err(CompletionError::Axioms(symbol.clone()));
+        }
+
+        let mut system = RewriteSystem {
+            rules: HashMap::new(),
+            normalizations: self.normalizations().clone(),
+        };
+
+        let mut pending = self
+            .sorted_equalities()
+            .into_iter()
+            .map(|(lhs, rhs)| (self.unfold(lhs), self.unfold(rhs)))
+            .collect::<Vec<_>>();
+        pending.sort();
+        pending.reverse();
+
+        while let Some((lhs, rhs)) = pending.pop() {
+            let lhs = system.rewrite(&lhs);
+            let rhs = system.rewrite(&rhs);
+
+            let (lhs, rhs) = match ordering.compare(&lhs, &rhs) {
+                Ordering::Equal if lhs == rhs => continue,
+                Ordering::Equal => return Err(CompletionError::Unorientable(lhs, rhs)),
+                Ordering::Greater => (lhs, rhs),
+                Ordering::Less => (rhs, lhs),
+            };
+
+            let mut collapsed = std::mem::take(&mut system.rules)
+                .into_iter()
+                .filter_map(|(other, rewritten)| {
+                    if contains(&other, &lhs) {
+                        return Some((other, rewritten));
+                    }
+
+                    system.rules.insert(other, rewritten);
+                    None
+                })
+                .collect::<Vec<_>>();
+            collapsed.sort();
+            pending.extend(collapsed);
+
+            system.rules.insert(lhs, rhs);
+
+            let composed = system
+                .rules
+                .iter()
+                .map(|(other, rewritten)| (other.clone(), system.rewrite(rewritten)))
+                .collect();
+            system.rules = composed;
+        }
+
+        Ok(system)
+    }
+}
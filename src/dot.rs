@@ -0,0 +1,138 @@
+//! Export of premises as Graphviz DOT graphs.
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Write};
+use std::hash::Hash;
+
+use crate::{equals, Premise, Term};
+
+struct Graph<Literal: Ord + Eq + Hash + Clone + Debug> {
+    nodes: Vec<Term<Literal>>,
+    indices: HashMap<Term<Literal>, usize>,
+    arguments: Vec<(usize, usize, usize)>,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Graph<Literal> {
+    fn node(&mut self, term: &Term<Literal>) -> usize {
+        if let Some(index) = self.indices.get(term) {
+            return *index;
+        }
+
+        let index = self.nodes.len();
+        self.nodes.push(term.clone());
+        self.indices.insert(term.clone(), index);
+
+        for (position, argument) in term.arguments().unwrap_or_default().iter().enumerate() {
+            let argument = self.node(argument);
+            self.arguments.push((index, argument, position));
+        }
+
+        index
+    }
+}
+
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Exports a premise as a Graphviz DOT graph.
+///
+/// Every distinct subterm of the equalities and of `terms` becomes a node labelled with its
+/// symbol, with an edge to each of its arguments labelled with the argument index. The
+/// equalities of the premise are drawn as undirected blue edges, and each
+/// [`Normalizable`](crate::Normalizable) term with a normalization has a dashed edge to its
+/// unfolding. Unfoldings are drawn one step deep so that recursive normalizations produce a
+/// finite graph.
+///
+/// Nodes that are [`equals`] to each other are grouped into a cluster per equivalence class.
+/// Since this checks every pair of nodes, exporting large premises can be slow.
+#[must_use]
+pub fn to_dot<Literal: Ord + Eq + Hash + Clone + Debug + Display>(
+    premise: &Premise<Literal>,
+    terms: &[Term<Literal>],
+) -> String {
+    let mut graph = Graph {
+        nodes: Vec::new(),
+        indices: HashMap::new(),
+        arguments: Vec::new(),
+    };
+
+    let equalities = premise.sorted_equalities();
+
+    let equalities = equalities
+        .into_iter()
+        .map(|(lhs, rhs)| (graph.node(lhs), graph.node(rhs)))
+        .collect::<Vec<_>>();
+
+    for term in terms {
+        graph.node(term);
+    }
+
+    let mut unfoldings = Vec::new();
+    for index in 0..graph.nodes.len() {
+        let Term::Normalizable(normalizable) = &graph.nodes[index] else {
+            continue;
+        };
+        let Some(unfolded) = premise
+            .get_normalization(&normalizable.symbol)
+            .and_then(|normalization| normalization.equivalence(&normalizable.arguments).ok())
+        else {
+            continue;
+        };
+
+        unfoldings.push((index, graph.node(&unfolded)));
+    }
+
+    let mut classes = Vec::<Vec<usize>>::new();
+    for (index, node) in graph.nodes.iter().enumerate() {
+        match classes
+            .iter_mut()
+            .find(|class| equals(&graph.nodes[class[0]], node, premise))
+        {
+            Some(class) => class.push(index),
+            None => classes.push(vec![index]),
+        }
+    }
+
+    let mut dot = String::from("digraph premise {\n    node [shape=box];\n");
+
+    let write_node = |dot: &mut String, indent: &str, index: usize| {
+        let label = match &graph.nodes[index] {
+            Term::Literal(literal) => literal.to_string(),
+            Term::Function(function) => function.symbol.to_string(),
+            Term::Normalizable(normalizable) => format!("@{}", normalizable.symbol),
+        };
+        let _ = writeln!(dot, "{indent}n{index} [label=\"{}\"];", escape(&label));
+    };
+
+    for (number, class) in classes.iter().filter(|class| class.len() > 1).enumerate() {
+        let _ = writeln!(
+            dot,
+            "    subgraph cluster_{number} {{\n        label=\"class {number}\";\n        \
+             style=rounded;"
+        );
+        for index in class {
+            write_node(&mut dot, "        ", *index);
+        }
+        dot.push_str("    }\n");
+    }
+    for class in classes.iter().filter(|class| class.len() == 1) {
+        write_node(&mut dot, "    ", class[0]);
+    }
+
+    for (parent, argument, position) in &graph.arguments {
+        let _ = writeln!(dot, "    n{parent} -> n{argument} [label=\"{position}\"];");
+    }
+    for (lhs, rhs) in equalities {
+        let _ = writeln!(dot, "    n{lhs} -> n{rhs} [dir=none, color=blue];");
+    }
+    for (normalizable, unfolded) in unfoldings {
+        let _ = writeln!(
+            dot,
+            "    n{normalizable} -> n{unfolded} [style=dashed, label=\"unfold\"];"
+        );
+    }
+
+    dot.push_str("}\n");
+    dot
+}
@@ -0,0 +1,88 @@
+//! Entailment and equivalence between premises.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::premise::any_subterm;
+use crate::{equals, Normalization, Premise, Term};
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Premise<Literal> {
+    /// Determines if everything that `other` states can be derived under this premise, so that
+    /// every equality that holds under `other` also holds under this premise.
+    ///
+    /// Every equality of `other` must be [`equals`] under this premise. Every normalization of
+    /// `other` must have a counterpart in this premise with the same number of parameters and
+    /// an equivalence that is equal to its own for any arguments. Every symbol with axioms in
+    /// `other` must have the same axioms in this premise.
+    ///
+    /// The check is conservative: if an equality, an axiom or the equivalence of another
+    /// normalization of this premise mentions a parameter as a literal, the parameter does not
+    /// stand for arbitrary arguments, so the equivalences are compared syntactically instead of
+    /// with [`equals`].
+    #[must_use]
+    pub fn entails(&self, other: &Self) -> bool {
+        other
+            .sorted_equalities()
+            .into_iter()
+            .all(|(lhs, rhs)| equals(lhs, rhs, self))
+            && other
+                .normalizations()
+                .iter()
+                .all(|(symbol, normalization)| self.entails_normalization(symbol, normalization))
+            && other
+                .axioms()
+                .iter()
+                .all(|(symbol, axioms)| self.get_axioms(symbol) == Some(axioms))
+    }
+
+    fn entails_normalization(&self, symbol: &Literal, other: &Normalization<Literal>) -> bool {
+        let Some(normalization) = self.get_normalization(symbol) else {
+            return false;
+        };
+        if normalization == other {
+            return true;
+        }
+
+        let Some(renamed) = other.renamed(&normalization.parameters) else {
+            return false;
+        };
+
+        let is_parameter = |term: &Term<Literal>| matches!(term, Term::Literal(literal) if normalization.parameters.contains(literal));
+
+        let constrained = self
+            .equalities()
+            .keys()
+            .chain(
+                self.axioms()
+                    .values()
+                    .filter_map(|axioms| axioms.identity.as_ref()),
+            )
+            .any(|term| any_subterm(term, &is_parameter))
+            || self
+                .normalizations()
+                .iter()
+                .filter(|(other_symbol, _)| *other_symbol != symbol)
+                .any(|(_, unfolded)| {
+                    let is_free = |term: &Term<Literal>| !matches!(term, Term::Literal(literal) if unfolded.parameters.contains(literal));
+
+                    any_subterm(&unfolded.equivalence, &|term| {
+                        is_parameter(term) && is_free(term)
+                    })
+                });
+        if constrained {
+            return renamed == normalization.equivalence;
+        }
+
+        equals(&normalization.equivalence, &renamed, self)
+    }
+}
+
+/// Determines if two premises entail each other, so that exactly the same equalities hold
+/// under both, see [`Premise::entails`].
+#[must_use]
+pub fn equivalent<Literal: Ord + Eq + Hash + Clone + Debug>(
+    premise1: &Premise<Literal>,
+    premise2: &Premise<Literal>,
+) -> bool {
+    premise1.entails(premise2) && premise2.entails(premise1)
+}
@@ -1,27 +1,132 @@
 //! Implementation of the equality algorithm in the First-Order Logic system.
 
+mod arithmetic;
+mod arrays;
+mod axioms;
+mod completion;
+mod dot;
+mod entailment;
+mod macros;
+mod merge;
+mod minimize;
+mod model;
+mod normalize;
+mod position;
 mod premise;
+#[cfg(feature = "serde")]
+mod serialization;
+mod slice;
+mod smtlib;
+mod snapshot;
 mod substitution;
+mod syntax;
 mod term;
+mod theory;
+mod tptp;
 mod visitor;
 
 use std::collections::HashSet;
 use std::fmt::Debug;
 use std::hash::Hash;
 
+use term::HeadKind;
+
+pub use arithmetic::equals_modulo_arithmetic;
+pub use arithmetic::Arithmetic;
+pub use arithmetic::IntegerLiteral;
+
+pub use arrays::Arrays;
+
+pub use axioms::SymbolAxioms;
+
+pub use completion::CompletionError;
+pub use completion::KnuthBendixOrdering;
+pub use completion::RewriteSystem;
+pub use completion::TermOrdering;
+
+pub use dot::to_dot;
+
+pub use entailment::equivalent;
+
+pub use merge::MergeError;
+
+pub use minimize::RemovalReason;
+pub use minimize::RemovedEquality;
+
+pub use model::counterexample;
+pub use model::Element;
+pub use model::Model;
+
+pub use normalize::DepthLimitExceeded;
+pub use normalize::Strategy;
+pub use normalize::DEFAULT_DEPTH_LIMIT;
+
+pub use position::Position;
+pub use position::Subterms;
+
 pub use premise::Normalization;
+pub use premise::NormalizationError;
 pub use premise::Premise;
 
+pub use smtlib::parse_smtlib;
+pub use smtlib::to_smtlib;
+pub use smtlib::SmtLibError;
+pub use smtlib::SmtLibErrorKind;
+pub use smtlib::SmtLibProblem;
+
+pub use snapshot::SnapshotError;
+pub use snapshot::SnapshotLiteral;
+pub use snapshot::SNAPSHOT_VERSION;
+
+pub use syntax::parse_premise;
+pub use syntax::parse_term;
+pub use syntax::ParseError;
+pub use syntax::ParseErrorKind;
+
+pub use term::Equality;
 pub use term::Function;
 pub use term::Normalizable;
 pub use term::Term;
 
+pub use theory::Conflict;
+pub use theory::Oracle;
+pub use theory::Theory;
+pub use theory::Unfolding;
+
+pub use tptp::parse_tptp;
+pub use tptp::TptpError;
+pub use tptp::TptpErrorKind;
+pub use tptp::TptpProblem;
+
+pub use visitor::Context;
+pub use visitor::Folder;
+pub use visitor::Visitor;
+pub use visitor::VisitorMut;
+
 fn equals_by_unification<Literal: Ord + Eq + Hash + Clone + Debug>(
     term1: &Term<Literal>,
     term2: &Term<Literal>,
     premise: &Premise<Literal>,
+    theories: &[&dyn Theory<Literal>],
     visited: &mut HashSet<(Term<Literal>, Term<Literal>)>,
 ) -> bool {
+    if !premise.axioms().is_empty() {
+        let (canonical1, canonical2) = (premise.canonicalize(term1), premise.canonicalize(term2));
+        if canonical1 != *term1 || canonical2 != *term2 {
+            return equals_by_unification(&canonical1, &canonical2, premise, theories, visited);
+        }
+    }
+
+    if let (Term::Function(function1), Term::Function(function2)) = (term1, term2) {
+        if function1.symbol == function2.symbol {
+            if let Some(axioms) = premise.get_axioms(&function1.symbol) {
+                return equals_modulo_axioms(
+                    function1, function2, axioms, premise, theories, visited,
+                );
+            }
+        }
+    }
+
     match (term1, term2) {
         (
             Term::Function(Function {
@@ -45,7 +150,7 @@ fn equals_by_unification<Literal: Ord + Eq + Hash + Clone + Debug>(
         ) if name1 == name2 && args1.len() == args2.len() => {
             let mut unification_succeed = true;
             for (arg1, arg2) in args1.iter().zip(args2.iter()) {
-                if !dfs(arg1, arg2, premise, visited) {
+                if !dfs(arg1, arg2, premise, theories, visited) {
                     unification_succeed = false;
                     break;
                 }
@@ -57,25 +162,184 @@ fn equals_by_unification<Literal: Ord + Eq + Hash + Clone + Debug>(
     }
 }
 
+fn equals_modulo_axioms<Literal: Ord + Eq + Hash + Clone + Debug>(
+    function1: &Function<Literal>,
+    function2: &Function<Literal>,
+    axioms: &SymbolAxioms<Literal>,
+    premise: &Premise<Literal>,
+    theories: &[&dyn Theory<Literal>],
+    visited: &mut HashSet<(Term<Literal>, Term<Literal>)>,
+) -> bool {
+    equals_by_arguments(
+        &function1.arguments,
+        &function2.arguments,
+        axioms,
+        premise,
+        theories,
+        visited,
+    ) || (axioms.associative
+        && equals_by_expansion(function1, function2, premise, theories, visited))
+}
+
+/// Compares the flattened arguments of two applications of a symbol with axioms.
+fn equals_by_arguments<Literal: Ord + Eq + Hash + Clone + Debug>(
+    arguments1: &[Term<Literal>],
+    arguments2: &[Term<Literal>],
+    axioms: &SymbolAxioms<Literal>,
+    premise: &Premise<Literal>,
+    theories: &[&dyn Theory<Literal>],
+    visited: &mut HashSet<(Term<Literal>, Term<Literal>)>,
+) -> bool {
+    // the arguments of an associative, commutative and idempotent symbol form a set
+    if axioms.commutative && axioms.associative && axioms.idempotent {
+        return covers(arguments1, arguments2, premise, theories, visited)
+            && covers(arguments2, arguments1, premise, theories, visited);
+    }
+
+    if arguments1.len() != arguments2.len() {
+        return false;
+    }
+
+    if axioms.commutative {
+        let mut used = vec![false; arguments2.len()];
+        return equals_by_permutation(
+            arguments1, arguments2, &mut used, premise, theories, visited,
+        );
+    }
+
+    for (argument1, argument2) in arguments1.iter().zip(arguments2) {
+        if !dfs(argument1, argument2, premise, theories, visited) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Replaces an argument of an application of an associative symbol with an application of the
+/// same symbol that the premise equates it with, so that the arguments of the latter are
+/// flattened into the former, and compares the result with the other application.
+///
+/// Without this, `U(d, c)` and `U(a, b, c)` would not be equal under `d = U(a, b)`, since the
+/// flattened applications have different numbers of arguments. An argument is only expanded if
+/// the result has at most as many arguments as the other application, which keeps the search
+/// finite but misses equalities that only hold once repeated arguments are removed.
+fn equals_by_expansion<Literal: Ord + Eq + Hash + Clone + Debug>(
+    function1: &Function<Literal>,
+    function2: &Function<Literal>,
+    premise: &Premise<Literal>,
+    theories: &[&dyn Theory<Literal>],
+    visited: &mut HashSet<(Term<Literal>, Term<Literal>)>,
+) -> bool {
+    let mut applications = premise
+        .terms_with_canonical_head((HeadKind::Function, &function1.symbol))
+        .map(|key| premise.canonical_form(key))
+        .collect::<Vec<_>>();
+    applications.sort();
+    applications.dedup();
+
+    for (expanded, other, swapped) in [(function1, function2, false), (function2, function1, true)]
+    {
+        let other_count = other.arguments.len();
+        let other = Term::Function(other.clone());
+
+        for (index, argument) in expanded.arguments.iter().enumerate() {
+            for application in &applications {
+                // expanding only adds arguments, unless they are repeated or an identity
+                let count =
+                    expanded.arguments.len() + application.arguments().map_or(0, <[_]>::len) - 1;
+                if count > other_count || !dfs(argument, application, premise, theories, visited) {
+                    continue;
+                }
+
+                let mut arguments = expanded.arguments.clone();
+                arguments.splice(
+                    index..=index,
+                    application.arguments().unwrap_or_default().iter().cloned(),
+                );
+                let expanded = premise.canonicalize(&Term::Function(Function {
+                    symbol: expanded.symbol.clone(),
+                    arguments,
+                }));
+
+                let equal = if swapped {
+                    dfs(&other, &expanded, premise, theories, visited)
+                } else {
+                    dfs(&expanded, &other, premise, theories, visited)
+                };
+                if equal {
+                    return true;
+                }
+            }
+        }
+    }
+
+    false
+}
+
+/// Checks if every term in `terms1` is equal to some term in `terms2`.
+fn covers<Literal: Ord + Eq + Hash + Clone + Debug>(
+    terms1: &[Term<Literal>],
+    terms2: &[Term<Literal>],
+    premise: &Premise<Literal>,
+    theories: &[&dyn Theory<Literal>],
+    visited: &mut HashSet<(Term<Literal>, Term<Literal>)>,
+) -> bool {
+    'outer: for term1 in terms1 {
+        for term2 in terms2 {
+            if dfs(term1, term2, premise, theories, visited) {
+                continue 'outer;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+/// Checks if `terms1` can be matched one-to-one with the terms in `terms2` that are not `used`.
+fn equals_by_permutation<Literal: Ord + Eq + Hash + Clone + Debug>(
+    terms1: &[Term<Literal>],
+    terms2: &[Term<Literal>],
+    used: &mut [bool],
+    premise: &Premise<Literal>,
+    theories: &[&dyn Theory<Literal>],
+    visited: &mut HashSet<(Term<Literal>, Term<Literal>)>,
+) -> bool {
+    let Some((first, rest)) = terms1.split_first() else {
+        return true;
+    };
+
+    for (index, term2) in terms2.iter().enumerate() {
+        if !used[index] && dfs(first, term2, premise, theories, visited) {
+            used[index] = true;
+            if equals_by_permutation(rest, terms2, used, premise, theories, visited) {
+                return true;
+            }
+            used[index] = false;
+        }
+    }
+
+    false
+}
+
 fn equals_by_normalization<Literal: Ord + Eq + Hash + Clone + Debug>(
     term1: &Term<Literal>,
     term2: &Term<Literal>,
     premise: &Premise<Literal>,
+    theories: &[&dyn Theory<Literal>],
     visited: &mut HashSet<(Term<Literal>, Term<Literal>)>,
 ) -> bool {
-    if let Term::Normalizable(term1) = term1 {
-        if let Some(normalization) = premise.get_normalization(&term1.symbol) {
-            if let Some(equivalence) = normalization.equivalence(&term1.arguments) {
-                return dfs(&equivalence, term2, premise, visited);
-            }
+    for theory in theories {
+        if let Some(normalized) = theory.normalize(term1, premise) {
+            return dfs(&normalized, term2, premise, theories, visited);
         }
     }
 
-    if let Term::Normalizable(term2) = term2 {
-        if let Some(normalization) = premise.get_normalization(&term2.symbol) {
-            if let Some(equivalence) = normalization.equivalence(&term2.arguments) {
-                return dfs(term1, &equivalence, premise, visited);
-            }
+    for theory in theories {
+        if let Some(normalized) = theory.normalize(term2, premise) {
+            return dfs(term1, &normalized, premise, theories, visited);
         }
     }
 
@@ -86,33 +350,54 @@ fn dfs<Literal: Eq + Ord + Hash + Clone + Debug>(
     term: &Term<Literal>,
     term2: &Term<Literal>,
     premise: &Premise<Literal>,
+    theories: &[&dyn Theory<Literal>],
     visited: &mut HashSet<(Term<Literal>, Term<Literal>)>,
 ) -> bool {
     if term == term2 {
         return true;
     }
 
+    if !premise.axioms().is_empty() {
+        let (canonical1, canonical2) = (premise.canonicalize(term), premise.canonicalize(term2));
+        if canonical1 != *term || canonical2 != *term2 {
+            return dfs(&canonical1, &canonical2, premise, theories, visited);
+        }
+    }
+
     if !visited.insert((term.clone(), term2.clone())) {
         // already visited
         return false;
     }
 
     // try to unify
-    if equals_by_unification(term, term2, premise, visited) {
+    if equals_by_unification(term, term2, premise, theories, visited) {
         visited.remove(&(term.clone(), term2.clone()));
         return true;
     }
 
     // try to normalize
-    if equals_by_normalization(term, term2, premise, visited) {
+    if equals_by_normalization(term, term2, premise, theories, visited) {
         visited.remove(&(term.clone(), term2.clone()));
         return true;
     }
 
+    // try the theories
+    for theory in theories {
+        let mut oracle = Oracle {
+            premise,
+            theories,
+            visited,
+        };
+        if theory.equals(term, term2, &mut oracle) {
+            visited.remove(&(term.clone(), term2.clone()));
+            return true;
+        }
+    }
+
     // try to look for a mapping in the premise
     if let Some(equivalences) = premise.equalities().get(term) {
         for equivalence in equivalences {
-            if dfs(equivalence, term2, premise, visited) {
+            if dfs(equivalence, term2, premise, theories, visited) {
                 visited.remove(&(term.clone(), term2.clone()));
                 return true;
             }
@@ -120,7 +405,7 @@ fn dfs<Literal: Eq + Ord + Hash + Clone + Debug>(
     }
     if let Some(equivalences) = premise.equalities().get(term2) {
         for equivalence in equivalences {
-            if dfs(term, equivalence, premise, visited) {
+            if dfs(term, equivalence, premise, theories, visited) {
                 visited.remove(&(term.clone(), term2.clone()));
                 return true;
             }
@@ -128,37 +413,39 @@ fn dfs<Literal: Eq + Ord + Hash + Clone + Debug>(
     }
 
     // try to unify/normalize the premise
-    for (key, values) in premise.equalities() {
-        if equals_by_unification(term, key, premise, visited) {
+    for key in candidates(term, term2, premise, theories) {
+        let values = &premise.equalities()[key];
+
+        if equals_by_unification(term, key, premise, theories, visited) {
             for value in values {
-                if dfs(value, term2, premise, visited) {
+                if dfs(value, term2, premise, theories, visited) {
                     visited.remove(&(term.clone(), term2.clone()));
                     return true;
                 }
             }
         }
 
-        if equals_by_unification(key, term2, premise, visited) {
+        if equals_by_unification(key, term2, premise, theories, visited) {
             for value in values {
-                if dfs(term, value, premise, visited) {
+                if dfs(term, value, premise, theories, visited) {
                     visited.remove(&(term.clone(), term2.clone()));
                     return true;
                 }
             }
         }
 
-        if equals_by_normalization(term, key, premise, visited) {
+        if equals_by_normalization(term, key, premise, theories, visited) {
             for value in values {
-                if dfs(value, term2, premise, visited) {
+                if dfs(value, term2, premise, theories, visited) {
                     visited.remove(&(term.clone(), term2.clone()));
                     return true;
                 }
             }
         }
 
-        if equals_by_normalization(key, term2, premise, visited) {
+        if equals_by_normalization(key, term2, premise, theories, visited) {
             for value in values {
-                if dfs(term, value, premise, visited) {
+                if dfs(term, value, premise, theories, visited) {
                     visited.remove(&(term.clone(), term2.clone()));
                     return true;
                 }
@@ -169,17 +456,157 @@ fn dfs<Literal: Eq + Ord + Hash + Clone + Debug>(
     false
 }
 
+/// Returns the terms with equalities in the premise that [`dfs`] may reach from `term1` or
+/// `term2` by unification or normalization.
+///
+/// Without theories besides [`Unfolding`], a term can only be reached if it has the same head
+/// as one of the terms, see [`Term::head`], or if it can be unfolded. With axioms, the terms
+/// are canonical and only the kind and symbol of the canonical form of the reached term have to
+/// match, as axioms may change the number of arguments. Every term with equalities is returned
+/// otherwise, or if one of the terms can be unfolded.
+fn candidates<'a, Literal: Ord + Eq + Hash + Clone + Debug>(
+    term1: &Term<Literal>,
+    term2: &Term<Literal>,
+    premise: &'a Premise<Literal>,
+    theories: &[&dyn Theory<Literal>],
+) -> Vec<&'a Term<Literal>> {
+    let unfoldable = |term| Unfolding.normalize(term, premise).is_some();
+
+    // the first theory is always `Unfolding`
+    if theories.len() > 1 || unfoldable(term1) || unfoldable(term2) {
+        return premise.equalities().keys().collect();
+    }
+
+    let mut candidates = HashSet::new();
+
+    if !premise.axioms().is_empty() {
+        let unfoldable_heads = premise
+            .normalizations()
+            .keys()
+            .map(|symbol| (HeadKind::Normalizable, symbol));
+
+        return [term1, term2]
+            .into_iter()
+            .map(|term| {
+                let (kind, symbol, _) = term.head();
+                (kind, symbol)
+            })
+            .chain(unfoldable_heads)
+            .flat_map(|head| premise.terms_with_canonical_head(head))
+            .filter(|term| candidates.insert(*term))
+            .collect();
+    }
+
+    let unfoldable_heads = premise
+        .normalizations()
+        .iter()
+        .map(|(symbol, normalization)| {
+            (
+                HeadKind::Normalizable,
+                symbol,
+                normalization.parameters.len(),
+            )
+        });
+
+    std::iter::once(term1.head())
+        .chain(std::iter::once(term2.head()))
+        .chain(unfoldable_heads)
+        .flat_map(|head| premise.terms_with_head(head))
+        .filter(|term| candidates.insert(*term))
+        .collect()
+}
+
 /// Determines if two terms are equal.
+///
+/// Only the [slice](Premise::slice) of the premise that can be relevant to the terms is
+/// searched.
 #[must_use]
 pub fn equals<Literal: Ord + Eq + Hash + Clone + Debug>(
     term1: &Term<Literal>,
     term2: &Term<Literal>,
     premise: &Premise<Literal>,
 ) -> bool {
+    // the search only needs the part of the premise that shares symbols with the terms
+    let premise = premise.slice(&[term1, term2]);
+
     // guaranteed to have at least 32K of stack
     let mut visited = HashSet::new();
 
-    dfs(term1, term2, premise, &mut visited)
+    dfs(term1, term2, &premise, &[&Unfolding], &mut visited)
+}
+
+/// Determines if two terms are equal, consulting the given theories in addition to the
+/// premise.
+///
+/// First, every equality of the premise is [propagated](Theory::propagate) through the
+/// theories, and the resulting equalities are added to the premise until no theory propagates
+/// a new equality, which the theories must guarantee to happen eventually. Each theory then
+/// [checks](Theory::check) the resulting premise once. If a theory reports a [`Conflict`], the
+/// premise is contradictory and every equality holds. Then, the terms are compared as in
+/// [`equals`], where at every step the theories may [normalize](Theory::normalize) a term or
+/// show two terms [equal](Theory::equals).
+///
+/// The built-in [`Unfolding`] theory is consulted before the given theories.
+#[must_use]
+pub fn equals_with_theories<Literal: Ord + Eq + Hash + Clone + Debug>(
+    term1: &Term<Literal>,
+    term2: &Term<Literal>,
+    premise: &Premise<Literal>,
+    theories: &[&dyn Theory<Literal>],
+) -> bool {
+    let theories = std::iter::once(&Unfolding as &dyn Theory<Literal>)
+        .chain(theories.iter().copied())
+        .collect::<Vec<_>>();
+
+    let mut extended = premise.clone();
+    let mut pending = premise
+        .sorted_equalities()
+        .into_iter()
+        .map(|(lhs, rhs)| (lhs.clone(), rhs.clone()))
+        .collect::<Vec<_>>();
+
+    while let Some((lhs, rhs)) = pending.pop() {
+        for theory in &theories {
+            let mut visited = HashSet::new();
+            let mut oracle = Oracle {
+                premise: &extended,
+                theories: &theories,
+                visited: &mut visited,
+            };
+
+            let Ok(propagated) = theory.propagate(&lhs, &rhs, &mut oracle) else {
+                return true;
+            };
+
+            for (lhs, rhs) in propagated {
+                let known = lhs == rhs
+                    || extended
+                        .equalities()
+                        .get(&lhs)
+                        .is_some_and(|equalities| equalities.contains(&rhs));
+
+                if !known {
+                    extended.insert(lhs.clone(), rhs.clone());
+                    pending.push((lhs, rhs));
+                }
+            }
+        }
+    }
+
+    for theory in &theories {
+        let mut visited = HashSet::new();
+        let mut oracle = Oracle {
+            premise: &extended,
+            theories: &theories,
+            visited: &mut visited,
+        };
+
+        if theory.check(&mut oracle).is_err() {
+            return true;
+        }
+    }
+
+    dfs(term1, term2, &extended, &theories, &mut HashSet::new())
 }
 
 #[cfg(test)]
@@ -0,0 +1,175 @@
+/// Constructs a [`Term`](crate::Term) from the syntax described in
+/// [`parse_premise`](crate::parse_premise).
+///
+/// Without a mapping, every symbol becomes a [`String`]. A mapping of type
+/// `FnMut(&'static str) -> Literal` can be given in brackets to construct terms of any literal
+/// type. A Rust expression evaluating to a term can be spliced in with braces, and integer
+/// literals such as `-1` are literals named by their digits.
+///
+/// ```
+/// use fol_equality::{term, Function, Term};
+///
+/// let term = term!(f(x, @norm(y)));
+/// assert_eq!(term.to_string(), "f(x, @norm(y))");
+///
+/// let lengths = term!([str::len] ab(c, {Term::Literal(7)}));
+/// assert_eq!(
+///     lengths,
+///     Term::Function(Function {
+///         symbol: 2,
+///         arguments: vec![Term::Literal(1), Term::Literal(7)],
+///     })
+/// );
+/// ```
+#[macro_export]
+macro_rules! term {
+    ([$map:expr] $($term:tt)+) => {{
+        let __map = &mut $map;
+        $crate::__term!(__map; $($term)+)
+    }};
+    ($($term:tt)+) => {
+        $crate::term!([::std::string::String::from] $($term)+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __term {
+    ($map:ident; { $term:expr }) => {
+        $term
+    };
+    ($map:ident; @ $symbol:ident ( $($arguments:tt)* )) => {
+        $crate::Term::Normalizable($crate::Normalizable {
+            symbol: $map(::std::stringify!($symbol)),
+            arguments: $crate::__term!(@arguments $map; [] [] $($arguments)*),
+        })
+    };
+    ($map:ident; @ $symbol:ident) => {
+        $crate::Term::Normalizable($crate::Normalizable {
+            symbol: $map(::std::stringify!($symbol)),
+            arguments: ::std::vec::Vec::new(),
+        })
+    };
+    ($map:ident; $symbol:ident ( $($arguments:tt)* )) => {
+        $crate::Term::Function($crate::Function {
+            symbol: $map(::std::stringify!($symbol)),
+            arguments: $crate::__term!(@arguments $map; [] [] $($arguments)*),
+        })
+    };
+    ($map:ident; $symbol:ident) => {
+        $crate::Term::Literal($map(::std::stringify!($symbol)))
+    };
+    ($map:ident; $integer:literal) => {
+        $crate::Term::Literal($map(::std::stringify!($integer)))
+    };
+    ($map:ident; - $integer:literal) => {
+        $crate::Term::Literal($map(::std::concat!("-", ::std::stringify!($integer))))
+    };
+
+    // splits the arguments at the commas, collecting the tokens of each argument in brackets
+    (@arguments $map:ident; [$($done:tt)*] [$($current:tt)+] , $($rest:tt)*) => {
+        $crate::__term!(@arguments $map; [$($done)* [$($current)+]] [] $($rest)*)
+    };
+    (@arguments $map:ident; [$($done:tt)*] [$($current:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__term!(@arguments $map; [$($done)*] [$($current)* $next] $($rest)*)
+    };
+    (@arguments $map:ident; [$($done:tt)*] [$($current:tt)+]) => {
+        $crate::__term!(@arguments $map; [$($done)* [$($current)+]] [])
+    };
+    (@arguments $map:ident; [$([$($argument:tt)+])*] []) => {
+        ::std::vec![$($crate::__term!($map; $($argument)+)),*]
+    };
+}
+
+/// Constructs a [`Premise`](crate::Premise) from the syntax described in
+/// [`parse_premise`](crate::parse_premise).
+///
+/// The `norm` keyword in front of a normalization may be omitted. Symbols are mapped the same
+/// way as in [`term!`](crate::term).
+///
+/// # Panics
+///
+/// Panics if a normalization is rejected by
+/// [`Premise::insert_normalization`](crate::Premise::insert_normalization), for example if a
+/// symbol is given more than one normalization.
+///
+/// ```
+/// use fol_equality::{equals, premise, term};
+///
+/// let premise = premise! {
+///     x = y;
+///     N(a) := f(a);
+/// };
+///
+/// assert!(equals(&term!(@N(x)), &term!(f(y)), &premise));
+/// ```
+#[macro_export]
+macro_rules! premise {
+    ([$map:expr] $($statements:tt)*) => {{
+        let __map = &mut $map;
+        #[allow(unused_mut)]
+        let mut __premise = $crate::Premise::default();
+        $crate::__premise!(__map, __premise; [] $($statements)*);
+        __premise
+    }};
+    ($($statements:tt)*) => {
+        $crate::premise!([::std::string::String::from] $($statements)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __premise {
+    // splits the statements at the semicolons
+    ($map:ident, $premise:ident; [$($current:tt)+] ; $($rest:tt)*) => {
+        $crate::__premise!(@statement $map, $premise; $($current)+);
+        $crate::__premise!($map, $premise; [] $($rest)*);
+    };
+    ($map:ident, $premise:ident; [$($current:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__premise!($map, $premise; [$($current)* $next] $($rest)*);
+    };
+    ($map:ident, $premise:ident; [$($current:tt)+]) => {
+        ::std::compile_error!(::std::concat!(
+            "expected `;` after `",
+            ::std::stringify!($($current)+),
+            "`"
+        ));
+    };
+    ($map:ident, $premise:ident; []) => {};
+
+    (@statement $map:ident, $premise:ident;
+        norm $symbol:ident ( $($parameter:ident),* $(,)? ) := $($equivalence:tt)+
+    ) => {
+        $crate::__premise!(@statement $map, $premise;
+            $symbol ( $($parameter),* ) := $($equivalence)+
+        )
+    };
+    (@statement $map:ident, $premise:ident;
+        $symbol:ident ( $($parameter:ident),* $(,)? ) := $($equivalence:tt)+
+    ) => {
+        if let ::std::result::Result::Err(error) = $premise.insert_normalization(
+            $map(::std::stringify!($symbol)),
+            ::std::vec![$($map(::std::stringify!($parameter))),*],
+            $crate::__term!($map; $($equivalence)+),
+        ) {
+            ::std::panic!(
+                "normalization `{}` is invalid: {:?}",
+                ::std::stringify!($symbol),
+                error,
+            );
+        }
+    };
+    (@statement $map:ident, $premise:ident; $($tokens:tt)+) => {
+        $crate::__premise!(@equality $map, $premise; [] $($tokens)+)
+    };
+
+    // splits an equality at the `=`
+    (@equality $map:ident, $premise:ident; [$($lhs:tt)+] = $($rhs:tt)+) => {{
+        let lhs = $crate::__term!($map; $($lhs)+);
+        let rhs = $crate::__term!($map; $($rhs)+);
+        $premise.insert(lhs, rhs);
+    }};
+    (@equality $map:ident, $premise:ident; [$($lhs:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__premise!(@equality $map, $premise; [$($lhs)* $next] $($rest)*)
+    };
+}
@@ -0,0 +1,244 @@
+//! Union and intersection of premises.
+
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+use crate::{equals, Equality, Normalization, NormalizationError, Premise, SymbolAxioms, Term};
+
+/// An error produced when merging premises, see [`Premise::union`] and
+/// [`Premise::extend_normalizations`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeError<Literal: Ord + Eq + Hash + Clone + Debug> {
+    /// The symbol has two normalizations that are not the same up to renaming of their
+    /// parameters.
+    ConflictingNormalizations {
+        /// The symbol of the normalizations.
+        symbol: Literal,
+
+        /// The normalization that the premise already has.
+        existing: Normalization<Literal>,
+
+        /// The normalization that was merged into the premise.
+        merged: Normalization<Literal>,
+    },
+
+    /// The symbol has two different declarations of axioms.
+    ConflictingAxioms {
+        /// The symbol of the axioms.
+        symbol: Literal,
+
+        /// The axioms that the premise already has.
+        existing: SymbolAxioms<Literal>,
+
+        /// The axioms that were merged into the premise.
+        merged: SymbolAxioms<Literal>,
+    },
+
+    /// The normalization is rejected by [`Premise::insert_normalization`].
+    InvalidNormalization(NormalizationError<Literal>),
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + Display> Display for MergeError<Literal> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ConflictingNormalizations { symbol, .. } => {
+                write!(f, "the normalizations of `{symbol}` disagree")
+            }
+            Self::ConflictingAxioms { symbol, .. } => {
+                write!(f, "the axioms of `{symbol}` disagree")
+            }
+            Self::InvalidNormalization(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + Display> std::error::Error for MergeError<Literal> {}
+
+/// Determines if two normalizations are the same up to renaming of their parameters.
+fn agrees<Literal: Ord + Eq + Hash + Clone + Debug>(
+    normalization1: &Normalization<Literal>,
+    normalization2: &Normalization<Literal>,
+) -> bool {
+    normalization1 == normalization2
+        || normalization2
+            .renamed(&normalization1.parameters)
+            .is_some_and(|renamed| renamed == normalization1.equivalence)
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Premise<Literal> {
+    /// Returns a premise with the equalities, normalizations and axioms of both premises.
+    ///
+    /// A symbol may have a normalization in both premises if the two are the same up to
+    /// renaming of their parameters, in which case the one of this premise is kept. Likewise, a
+    /// symbol may have axioms in both premises if they are the same.
+    ///
+    /// # Errors
+    ///
+    /// - [`MergeError::ConflictingNormalizations`] if the premises have disagreeing
+    ///   normalizations for a symbol.
+    /// - [`MergeError::ConflictingAxioms`] if the premises have different axioms for a symbol.
+    ///
+    /// If several symbols conflict, the smallest one is reported.
+    pub fn union(&self, other: &Self) -> Result<Self, MergeError<Literal>> {
+        let mut union = self.clone();
+        union.extend(
+            other
+                .sorted_equalities()
+                .into_iter()
+                .map(|(lhs, rhs)| (lhs.clone(), rhs.clone())),
+        );
+
+        let mut normalizations = other.normalizations().iter().collect::<Vec<_>>();
+        normalizations.sort_by_key(|(symbol, _)| *symbol);
+        union.extend_normalizations(
+            normalizations
+                .into_iter()
+                .map(|(symbol, normalization)| (symbol.clone(), normalization.clone())),
+        )?;
+
+        let mut axioms = other.axioms().iter().collect::<Vec<_>>();
+        axioms.sort_by_key(|(symbol, _)| *symbol);
+
+        for (symbol, merged) in axioms {
+            match union.get_axioms(symbol) {
+                Some(existing) if existing != merged => {
+                    return Err(MergeError::ConflictingAxioms {
+                        symbol: symbol.clone(),
+                        existing: existing.clone(),
+                        merged: merged.clone(),
+                    });
+                }
+                Some(_) => {}
+                None => {
+                    union.insert_axioms(symbol.clone(), merged.clone());
+                }
+            }
+        }
+
+        Ok(union)
+    }
+
+    /// Returns a premise of the equalities that hold under both premises, along with the
+    /// normalizations and axioms that both premises share.
+    ///
+    /// Infinitely many equalities may hold under both premises, so only those between the terms
+    /// of the equalities of either premise, and their subterms, are considered. Those terms are
+    /// grouped into the classes of terms that are [`equals`] under both premises, and each
+    /// class is stated as a chain of equalities. The result may contain equalities that follow
+    /// from the others, see [`Premise::minimize`].
+    ///
+    /// Normalizations are shared if both premises have the same normalization up to renaming of
+    /// its parameters.
+    ///
+    /// # Panics
+    ///
+    /// Never panics in practice: the shared normalizations are copied from this premise, which
+    /// has already accepted them.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Self::default();
+
+        let mut normalizations = self.normalizations().iter().collect::<Vec<_>>();
+        normalizations.sort_by_key(|(symbol, _)| *symbol);
+
+        for (symbol, normalization) in normalizations {
+            if other
+                .get_normalization(symbol)
+                .is_some_and(|shared| agrees(normalization, shared))
+            {
+                intersection
+                    .insert_normalization(
+                        symbol.clone(),
+                        normalization.parameters.clone(),
+                        normalization.equivalence.clone(),
+                    )
+                    .expect("the normalization is accepted by this premise");
+            }
+        }
+
+        for (symbol, axioms) in self.axioms() {
+            if other.get_axioms(symbol) == Some(axioms) {
+                intersection.insert_axioms(symbol.clone(), axioms.clone());
+            }
+        }
+
+        let terms = self
+            .equalities()
+            .keys()
+            .chain(other.equalities().keys())
+            .flat_map(Term::subterms)
+            .map(|(_, term)| term)
+            .collect::<BTreeSet<_>>();
+
+        let mut representatives = Vec::<&Term<Literal>>::new();
+
+        for term in terms {
+            let representative = representatives.iter().find(|representative| {
+                equals(representative, term, self) && equals(representative, term, other)
+            });
+
+            match representative {
+                Some(representative) => {
+                    intersection.insert((*representative).clone(), term.clone());
+                }
+                None => representatives.push(term),
+            }
+        }
+
+        intersection
+    }
+
+    /// Inserts normalizations into the premise.
+    ///
+    /// A normalization for a symbol that already has the same normalization, up to renaming of
+    /// its parameters, is skipped.
+    ///
+    /// # Errors
+    ///
+    /// - [`MergeError::ConflictingNormalizations`] if a symbol already has a different
+    ///   normalization.
+    /// - [`MergeError::InvalidNormalization`] if the normalization is rejected by
+    ///   [`Premise::insert_normalization`].
+    ///
+    /// The normalizations before the erroneous one are inserted.
+    pub fn extend_normalizations(
+        &mut self,
+        normalizations: impl IntoIterator<Item = (Literal, Normalization<Literal>)>,
+    ) -> Result<(), MergeError<Literal>> {
+        for (symbol, merged) in normalizations {
+            if let Some(existing) = self.get_normalization(&symbol) {
+                if agrees(existing, &merged) {
+                    continue;
+                }
+
+                return Err(MergeError::ConflictingNormalizations {
+                    existing: existing.clone(),
+                    symbol,
+                    merged,
+                });
+            }
+
+            self.insert_normalization(symbol, merged.parameters, merged.equivalence)
+                .map_err(MergeError::InvalidNormalization)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Extend<Equality<Literal>> for Premise<Literal> {
+    fn extend<T: IntoIterator<Item = Equality<Literal>>>(&mut self, equalities: T) {
+        for (term1, term2) in equalities {
+            self.insert(term1, term2);
+        }
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> FromIterator<Equality<Literal>>
+    for Premise<Literal>
+{
+    fn from_iter<T: IntoIterator<Item = Equality<Literal>>>(equalities: T) -> Self {
+        Self::new_with_equalities(equalities)
+    }
+}
@@ -0,0 +1,142 @@
+//! Removal of redundant equalities from premises.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::{equals, Equality, Premise, Term};
+
+/// The reason why [`Premise::minimize`] removed an equality.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemovalReason<Literal: Ord + Eq + Hash + Clone + Debug> {
+    /// Both sides of the equality are the same term.
+    Reflexive,
+
+    /// The equality follows from the normalizations, the axioms and the given equalities of the
+    /// minimized premise.
+    ///
+    /// The support is minimal: the equality does not follow if any of its equalities is left
+    /// out. It is empty if the equality follows from the normalizations and axioms alone.
+    Derivable {
+        /// The equalities of the minimized premise that the equality follows from.
+        support: Vec<Equality<Literal>>,
+    },
+}
+
+/// An equality removed by [`Premise::minimize`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemovedEquality<Literal: Ord + Eq + Hash + Clone + Debug> {
+    /// The removed equality.
+    pub equality: Equality<Literal>,
+
+    /// The reason why the equality was removed.
+    pub reason: RemovalReason<Literal>,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Premise<Literal> {
+    /// Returns an equivalent premise in which no equality follows from the others, along with
+    /// the removed equalities in the order they were removed.
+    ///
+    /// The equalities are considered one at a time in sorted order, and each one that follows
+    /// from the equalities still left is removed. Normalizations and axioms are kept as they
+    /// are. Which equalities are kept depends on the order, so the result is a minimal premise
+    /// rather than the smallest one.
+    ///
+    /// Since every check runs [`equals`], minimizing a premise with many equalities can be
+    /// slow.
+    #[must_use]
+    pub fn minimize(&self) -> (Self, Vec<RemovedEquality<Literal>>) {
+        let mut equalities = self
+            .sorted_equalities()
+            .into_iter()
+            .map(|(lhs, rhs)| (lhs.clone(), rhs.clone()))
+            .collect::<Vec<_>>();
+
+        let mut removed = Vec::new();
+        let mut index = 0;
+
+        while index < equalities.len() {
+            let (lhs, rhs) = &equalities[index];
+
+            if lhs == rhs {
+                removed.push((equalities.remove(index), true));
+                continue;
+            }
+
+            let rest = self.with_equalities(
+                equalities[..index]
+                    .iter()
+                    .chain(&equalities[index + 1..])
+                    .cloned(),
+            );
+
+            if equals(lhs, rhs, &rest) {
+                removed.push((equalities.remove(index), false));
+            } else {
+                index += 1;
+            }
+        }
+
+        let removed = removed
+            .into_iter()
+            .map(|(equality, reflexive)| {
+                let reason = if reflexive {
+                    RemovalReason::Reflexive
+                } else {
+                    RemovalReason::Derivable {
+                        support: self.support(&equality, equalities.clone()),
+                    }
+                };
+
+                RemovedEquality { equality, reason }
+            })
+            .collect();
+
+        (self.with_equalities(equalities), removed)
+    }
+
+    /// Returns a minimal set of equalities of the premise that, together with its
+    /// normalizations and axioms, show the two terms equal, or `None` if the terms are not
+    /// [`equals`] under the premise.
+    ///
+    /// The equalities are left out one at a time in sorted order as long as the terms stay
+    /// equal, so the result is a minimal set rather than the smallest one.
+    #[must_use]
+    pub fn explain(
+        &self,
+        term1: &Term<Literal>,
+        term2: &Term<Literal>,
+    ) -> Option<Vec<Equality<Literal>>> {
+        if !equals(term1, term2, self) {
+            return None;
+        }
+
+        let equalities = self
+            .sorted_equalities()
+            .into_iter()
+            .map(|(lhs, rhs)| (lhs.clone(), rhs.clone()))
+            .collect::<Vec<_>>();
+
+        Some(self.support(&(term1.clone(), term2.clone()), equalities))
+    }
+
+    /// Removes equalities from `support` one at a time as long as `equality` still follows from
+    /// the rest.
+    fn support(
+        &self,
+        (lhs, rhs): &Equality<Literal>,
+        mut support: Vec<Equality<Literal>>,
+    ) -> Vec<Equality<Literal>> {
+        let mut index = 0;
+
+        while index < support.len() {
+            let removed = support.remove(index);
+
+            if !equals(lhs, rhs, &self.with_equalities(support.iter().cloned())) {
+                support.insert(index, removed);
+                index += 1;
+            }
+        }
+
+        support
+    }
+}
@@ -0,0 +1,326 @@
+//! Finite counterexample models for equalities that do not hold.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::{Function, Normalizable, Normalization, Premise, Term};
+
+/// An element of the domain of a [`Model`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Element(usize);
+
+impl Element {
+    /// Returns the index of the element, between zero and the [size](Model::size) of its
+    /// model.
+    #[must_use]
+    pub const fn index(self) -> usize {
+        self.0
+    }
+}
+
+type Table = HashMap<Vec<Element>, Element>;
+
+/// A finite interpretation of terms, see [`counterexample`].
+///
+/// Every literal and every application of a function symbol is interpreted as an element of
+/// the domain by lookup tables, where anything not in a table is interpreted as the
+/// [default element](Self::default_element). Normalizable terms are interpreted by unfolding
+/// their normalization, or by lookup tables if their symbol has no normalization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Model<Literal: Ord + Eq + Hash + Clone + Debug> {
+    classes: Vec<Vec<Term<Literal>>>,
+    literals: HashMap<Literal, Element>,
+    functions: HashMap<Literal, Table>,
+    normalizables: HashMap<Literal, Table>,
+    normalizations: HashMap<Literal, Normalization<Literal>>,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Model<Literal> {
+    /// Returns the number of elements in the domain.
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.classes.len() + 1
+    }
+
+    /// Returns the element that every term outside of the lookup tables is interpreted as.
+    #[must_use]
+    pub const fn default_element(&self) -> Element {
+        Element(self.classes.len())
+    }
+
+    /// Returns the terms of the premise and the query that are interpreted as the element,
+    /// sorted.
+    ///
+    /// Normalizable terms with a normalization appear unfolded.
+    #[must_use]
+    pub fn class(&self, element: Element) -> &[Term<Literal>] {
+        self.classes
+            .get(element.0)
+            .map_or(&[], |terms| terms.as_slice())
+    }
+
+    /// Returns the interpretation of the literals.
+    #[must_use]
+    pub const fn literals(&self) -> &HashMap<Literal, Element> {
+        &self.literals
+    }
+
+    /// Returns the lookup table of each function symbol, from the interpretation of the
+    /// arguments to the interpretation of the application.
+    #[must_use]
+    pub const fn functions(&self) -> &HashMap<Literal, HashMap<Vec<Element>, Element>> {
+        &self.functions
+    }
+
+    /// Returns the lookup table of each normalizable symbol that is interpreted without a
+    /// normalization.
+    #[must_use]
+    pub const fn normalizables(&self) -> &HashMap<Literal, HashMap<Vec<Element>, Element>> {
+        &self.normalizables
+    }
+
+    /// Interprets a term as an element of the domain.
+    #[must_use]
+    pub fn evaluate(&self, term: &Term<Literal>) -> Element {
+        self.evaluate_in(term, &HashMap::new())
+    }
+
+    fn evaluate_in(
+        &self,
+        term: &Term<Literal>,
+        parameters: &HashMap<&Literal, Element>,
+    ) -> Element {
+        let lookup = |tables: &HashMap<Literal, Table>, symbol: &Literal, arguments: &[Element]| {
+            tables
+                .get(symbol)
+                .and_then(|table| table.get(arguments))
+                .copied()
+                .unwrap_or_else(|| self.default_element())
+        };
+
+        match term {
+            Term::Literal(literal) => parameters
+                .get(literal)
+                .or_else(|| self.literals.get(literal))
+                .copied()
+                .unwrap_or_else(|| self.default_element()),
+            Term::Function(Function { symbol, arguments }) => {
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| self.evaluate_in(argument, parameters))
+                    .collect::<Vec<_>>();
+
+                lookup(&self.functions, symbol, &arguments)
+            }
+            Term::Normalizable(Normalizable { symbol, arguments }) => {
+                let elements = arguments
+                    .iter()
+                    .map(|argument| self.evaluate_in(argument, parameters))
+                    .collect::<Vec<_>>();
+
+                match self.normalizations.get(symbol) {
+                    Some(normalization) if normalization.parameters.len() == elements.len() => {
+                        let parameters = normalization.parameters.iter().zip(elements).collect();
+
+                        self.evaluate_in(&normalization.equivalence, &parameters)
+                    }
+                    _ => lookup(&self.normalizables, symbol, &elements),
+                }
+            }
+        }
+    }
+
+    /// Determines if every equality of the premise holds in the model, and every
+    /// normalization of the premise is the one the model unfolds its symbol with.
+    #[must_use]
+    pub fn satisfies(&self, premise: &Premise<Literal>) -> bool {
+        premise.normalizations() == &self.normalizations
+            && premise.equalities().iter().all(|(lhs, equalities)| {
+                let element = self.evaluate(lhs);
+                equalities.iter().all(|rhs| self.evaluate(rhs) == element)
+            })
+    }
+}
+
+/// The congruence closure over the subterms of a set of terms.
+struct Closure<Literal: Ord + Eq + Hash + Clone + Debug> {
+    terms: Vec<Term<Literal>>,
+    indices: HashMap<Term<Literal>, usize>,
+    arguments: Vec<Vec<usize>>,
+    parents: Vec<usize>,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Closure<Literal> {
+    fn node(&mut self, term: &Term<Literal>) -> usize {
+        if let Some(index) = self.indices.get(term) {
+            return *index;
+        }
+
+        let arguments = term
+            .arguments()
+            .unwrap_or_default()
+            .iter()
+            .map(|argument| self.node(argument))
+            .collect();
+
+        let index = self.terms.len();
+        self.terms.push(term.clone());
+        self.indices.insert(term.clone(), index);
+        self.arguments.push(arguments);
+        self.parents.push(index);
+
+        index
+    }
+
+    fn find(&mut self, index: usize) -> usize {
+        let parent = self.parents[index];
+        if parent == index {
+            return index;
+        }
+
+        let root = self.find(parent);
+        self.parents[index] = root;
+        root
+    }
+
+    fn union(&mut self, index1: usize, index2: usize) -> bool {
+        let (root1, root2) = (self.find(index1), self.find(index2));
+        if root1 == root2 {
+            return false;
+        }
+
+        self.parents[root1.max(root2)] = root1.min(root2);
+        true
+    }
+
+    /// Merges applications of the same symbol to equal arguments until nothing changes.
+    fn close(&mut self) {
+        loop {
+            let mut signatures = HashMap::new();
+            let mut merged = false;
+
+            for index in 0..self.terms.len() {
+                let (function, symbol) = match &self.terms[index] {
+                    Term::Literal(_) => continue,
+                    Term::Function(function) => (true, function.symbol.clone()),
+                    Term::Normalizable(normalizable) => (false, normalizable.symbol.clone()),
+                };
+                let arguments = self.arguments[index].clone();
+                let arguments = arguments
+                    .into_iter()
+                    .map(|argument| self.find(argument))
+                    .collect::<Vec<_>>();
+
+                match signatures.get(&(function, symbol.clone(), arguments.clone())) {
+                    Some(&other) => merged |= self.union(index, other),
+                    None => {
+                        signatures.insert((function, symbol, arguments), index);
+                    }
+                }
+            }
+
+            if !merged {
+                break;
+            }
+        }
+    }
+}
+
+/// Builds a finite model of the premise in which the two terms are interpreted differently,
+/// showing that they are not equal.
+///
+/// The model is the congruence closure of the equalities of the premise over the subterms of
+/// the premise and of the two terms, after unfolding every normalization. Each equivalence
+/// class becomes an element of the domain, and one more element interprets everything else.
+///
+/// Returns `None` if the terms are equal, and also if the premise declares
+/// [axioms](Premise::insert_axioms) or a recursive normalization, since the lookup tables of
+/// the model cannot represent those.
+#[must_use]
+pub fn counterexample<Literal: Ord + Eq + Hash + Clone + Debug>(
+    term1: &Term<Literal>,
+    term2: &Term<Literal>,
+    premise: &Premise<Literal>,
+) -> Option<Model<Literal>> {
+    if !premise.axioms().is_empty() || premise.recursive_normalization().is_some() {
+        return None;
+    }
+
+    let mut closure = Closure {
+        terms: Vec::new(),
+        indices: HashMap::new(),
+        arguments: Vec::new(),
+        parents: Vec::new(),
+    };
+
+    let equalities = premise.sorted_equalities();
+
+    for (lhs, rhs) in equalities {
+        let lhs = closure.node(&premise.unfold(lhs));
+        let rhs = closure.node(&premise.unfold(rhs));
+        closure.union(lhs, rhs);
+    }
+
+    let node1 = closure.node(&premise.unfold(term1));
+    let node2 = closure.node(&premise.unfold(term2));
+
+    closure.close();
+
+    if closure.find(node1) == closure.find(node2) {
+        return None;
+    }
+
+    let mut elements = HashMap::new();
+    let mut model = Model {
+        classes: Vec::new(),
+        literals: HashMap::new(),
+        functions: HashMap::new(),
+        normalizables: HashMap::new(),
+        normalizations: premise.normalizations().clone(),
+    };
+
+    for index in 0..closure.terms.len() {
+        let root = closure.find(index);
+        let element = *elements.entry(root).or_insert_with(|| {
+            model.classes.push(Vec::new());
+            Element(model.classes.len() - 1)
+        });
+        model.classes[element.0].push(closure.terms[index].clone());
+    }
+
+    for index in 0..closure.terms.len() {
+        let element = elements[&closure.find(index)];
+        let arguments = closure.arguments[index]
+            .clone()
+            .into_iter()
+            .map(|argument| elements[&closure.find(argument)])
+            .collect::<Vec<_>>();
+
+        match &closure.terms[index] {
+            Term::Literal(literal) => {
+                model.literals.insert(literal.clone(), element);
+            }
+            Term::Function(function) => {
+                model
+                    .functions
+                    .entry(function.symbol.clone())
+                    .or_default()
+                    .insert(arguments, element);
+            }
+            Term::Normalizable(normalizable) => {
+                model
+                    .normalizables
+                    .entry(normalizable.symbol.clone())
+                    .or_default()
+                    .insert(arguments, element);
+            }
+        }
+    }
+
+    for class in &mut model.classes {
+        class.sort();
+    }
+
+    Some(model)
+}
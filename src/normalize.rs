@@ -0,0 +1,215 @@
+//! Reduction of normalizable terms with a selectable strategy.
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+use crate::{Function, Normalizable, Premise, Term};
+
+/// The number of nested unfoldings allowed by [`Premise::normalize`].
+pub const DEFAULT_DEPTH_LIMIT: usize = 256;
+
+/// The order in which [`Premise::normalize`] unfolds normalizable terms.
+///
+/// All strategies reach the same result for terms whose unfolding terminates, except that
+/// [`Strategy::Lazy`] does not reduce the arguments of the result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Strategy {
+    /// Reduces the arguments of a term before unfolding the term itself.
+    ///
+    /// The arguments are reduced once, however often the equivalence mentions them.
+    #[default]
+    Innermost,
+
+    /// Unfolds a term before reducing the arguments of the result.
+    ///
+    /// Arguments that the equivalence mentions more than once are still reduced once, since
+    /// repeated subterms are remembered.
+    Outermost,
+
+    /// Unfolds a term only until it is no longer a normalizable term with a normalization,
+    /// leaving its arguments as they are.
+    ///
+    /// This shows one level of the expanded form, and terminates even for most recursive
+    /// normalizations.
+    Lazy,
+}
+
+/// An error produced when normalizing a term takes more nested unfoldings than allowed, see
+/// [`Premise::normalize_with_limit`].
+///
+/// This usually means that a normalization is recursive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepthLimitExceeded<Literal: Ord + Eq + Hash + Clone + Debug> {
+    /// The number of nested unfoldings that was allowed.
+    pub limit: usize,
+
+    /// The term that would have been unfolded beyond the limit.
+    pub term: Term<Literal>,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + Display> Display for DepthLimitExceeded<Literal> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unfolding `{}` exceeds the limit of {} nested unfoldings",
+            self.term, self.limit
+        )
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + Display> std::error::Error
+    for DepthLimitExceeded<Literal>
+{
+}
+
+struct Normalizer<'a, Literal: Ord + Eq + Hash + Clone + Debug> {
+    premise: &'a Premise<Literal>,
+    strategy: Strategy,
+    limit: usize,
+
+    /// The results of the terms normalized so far, so that repeated subterms are only
+    /// normalized once.
+    normalized: HashMap<Term<Literal>, Term<Literal>>,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Normalizer<'_, Literal> {
+    fn normalize(
+        &mut self,
+        term: &Term<Literal>,
+        depth: usize,
+    ) -> Result<Term<Literal>, DepthLimitExceeded<Literal>> {
+        if let Some(normalized) = self.normalized.get(term) {
+            return Ok(normalized.clone());
+        }
+
+        // unfoldings may nest deeply before the limit is reached
+        let normalized = stacker::maybe_grow(32 * 1024, 1024 * 1024, || self.reduce(term, depth))?;
+
+        self.normalized.insert(term.clone(), normalized.clone());
+        Ok(normalized)
+    }
+
+    fn reduce(
+        &mut self,
+        term: &Term<Literal>,
+        depth: usize,
+    ) -> Result<Term<Literal>, DepthLimitExceeded<Literal>> {
+        Ok(match self.strategy {
+            Strategy::Innermost => {
+                let term = self.normalize_arguments(term, depth)?;
+
+                match self.unfold(&term, depth)? {
+                    Some(unfolded) => self.normalize(&unfolded, depth + 1)?,
+                    None => term,
+                }
+            }
+            Strategy::Outermost => match self.unfold(term, depth)? {
+                Some(unfolded) => self.normalize(&unfolded, depth + 1)?,
+                None => self.normalize_arguments(term, depth)?,
+            },
+            Strategy::Lazy => match self.unfold(term, depth)? {
+                Some(unfolded) => self.normalize(&unfolded, depth + 1)?,
+                None => term.clone(),
+            },
+        })
+    }
+
+    fn normalize_arguments(
+        &mut self,
+        term: &Term<Literal>,
+        depth: usize,
+    ) -> Result<Term<Literal>, DepthLimitExceeded<Literal>> {
+        Ok(match term {
+            Term::Literal(_) => term.clone(),
+            Term::Function(Function { symbol, arguments }) => Term::Function(Function {
+                symbol: symbol.clone(),
+                arguments: arguments
+                    .iter()
+                    .map(|argument| self.normalize(argument, depth))
+                    .collect::<Result<_, _>>()?,
+            }),
+            Term::Normalizable(Normalizable { symbol, arguments }) => {
+                Term::Normalizable(Normalizable {
+                    symbol: symbol.clone(),
+                    arguments: arguments
+                        .iter()
+                        .map(|argument| self.normalize(argument, depth))
+                        .collect::<Result<_, _>>()?,
+                })
+            }
+        })
+    }
+
+    /// Unfolds the term one step if it is a normalizable term with a normalization.
+    fn unfold(
+        &self,
+        term: &Term<Literal>,
+        depth: usize,
+    ) -> Result<Option<Term<Literal>>, DepthLimitExceeded<Literal>> {
+        let Term::Normalizable(normalizable) = term else {
+            return Ok(None);
+        };
+        let Some(unfolded) = self
+            .premise
+            .get_normalization(&normalizable.symbol)
+            .and_then(|normalization| normalization.equivalence(&normalizable.arguments).ok())
+        else {
+            return Ok(None);
+        };
+
+        if depth >= self.limit {
+            return Err(DepthLimitExceeded {
+                limit: self.limit,
+                term: term.clone(),
+            });
+        }
+
+        Ok(Some(unfolded))
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Premise<Literal> {
+    /// Reduces a term by unfolding its normalizable terms into the equivalences of their
+    /// normalizations, in the order given by the strategy.
+    ///
+    /// Normalizable terms without a normalization, or applied to the wrong number of arguments,
+    /// are left as they are. The equalities of the premise are not used.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DepthLimitExceeded`] if more than [`DEFAULT_DEPTH_LIMIT`] unfoldings are
+    /// nested inside each other.
+    pub fn normalize(
+        &self,
+        term: &Term<Literal>,
+        strategy: Strategy,
+    ) -> Result<Term<Literal>, DepthLimitExceeded<Literal>> {
+        self.normalize_with_limit(term, strategy, DEFAULT_DEPTH_LIMIT)
+    }
+
+    /// Reduces a term as in [`Premise::normalize`], allowing at most `limit` unfoldings to be
+    /// nested inside each other.
+    ///
+    /// A term is only normalized once even if it appears many times, so the limit counts
+    /// unfoldings nested inside each other rather than all unfoldings.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DepthLimitExceeded`] if more than `limit` unfoldings are nested inside each
+    /// other.
+    pub fn normalize_with_limit(
+        &self,
+        term: &Term<Literal>,
+        strategy: Strategy,
+        limit: usize,
+    ) -> Result<Term<Literal>, DepthLimitExceeded<Literal>> {
+        Normalizer {
+            premise: self,
+            strategy,
+            limit,
+            normalized: HashMap::new(),
+        }
+        .normalize(term, 0)
+    }
+}
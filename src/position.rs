@@ -0,0 +1,153 @@
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+use crate::{Function, Normalizable, Term};
+
+/// Represents a position of a subterm in a term.
+///
+/// A position is the path of argument indices leading from the root of the term to the subterm.
+/// The indices are zero-based, for example, in `f(x, g(y, z))` the position `[1, 0]` points to
+/// `y`, the first argument of the second argument.
+///
+/// The position with an empty path is the root position, pointing to the term itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position(Vec<usize>);
+
+impl Position {
+    /// Returns the root position.
+    #[must_use]
+    pub const fn root() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Returns `true` if this is the root position.
+    #[must_use]
+    pub const fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the path of argument indices from the root.
+    #[must_use]
+    pub fn indices(&self) -> &[usize] {
+        &self.0
+    }
+
+    /// Returns the position of the `index`-th argument of the term at this position.
+    #[must_use]
+    pub fn child(&self, index: usize) -> Self {
+        let mut indices = self.0.clone();
+        indices.push(index);
+
+        Self(indices)
+    }
+
+    /// Returns the position of the term whose argument is at this position.
+    ///
+    /// Returns `None` if this is the root position.
+    #[must_use]
+    pub fn parent(&self) -> Option<Self> {
+        self.0.split_last().map(|(_, rest)| Self(rest.to_vec()))
+    }
+}
+
+impl From<Vec<usize>> for Position {
+    fn from(indices: Vec<usize>) -> Self {
+        Self(indices)
+    }
+}
+
+impl FromIterator<usize> for Position {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Display for Position {
+    /// Formats the root position as `ε` and other positions as their dot-separated indices,
+    /// for example, `1.0`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((first, rest)) = self.0.split_first() else {
+            return write!(f, "ε");
+        };
+
+        write!(f, "{first}")?;
+        for index in rest {
+            write!(f, ".{index}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An iterator over all the subterms of a term together with their positions.
+///
+/// The subterms are yielded in pre-order, starting with the term itself at the root position.
+///
+/// See [`Term::subterms`].
+#[derive(Debug, Clone)]
+pub struct Subterms<'a, Literal: Ord + Eq + Hash + Clone + Debug> {
+    stack: Vec<(Position, &'a Term<Literal>)>,
+}
+
+impl<'a, Literal: Ord + Eq + Hash + Clone + Debug> Iterator for Subterms<'a, Literal> {
+    type Item = (Position, &'a Term<Literal>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (position, term) = self.stack.pop()?;
+
+        if let Some(arguments) = term.arguments() {
+            self.stack.extend(
+                arguments
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .map(|(index, argument)| (position.child(index), argument)),
+            );
+        }
+
+        Some((position, term))
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Term<Literal> {
+    /// Returns the subterm at the given position.
+    ///
+    /// Returns `None` if the position does not exist in the term.
+    #[must_use]
+    pub fn subterm_at(&self, position: &Position) -> Option<&Self> {
+        position.indices().iter().try_fold(self, |term, index| {
+            term.arguments().and_then(|arguments| arguments.get(*index))
+        })
+    }
+
+    /// Returns a mutable reference to the subterm at the given position.
+    ///
+    /// Returns `None` if the position does not exist in the term.
+    pub fn subterm_at_mut(&mut self, position: &Position) -> Option<&mut Self> {
+        position
+            .indices()
+            .iter()
+            .try_fold(self, |term, index| match term {
+                Self::Literal(_) => None,
+                Self::Function(Function { arguments, .. })
+                | Self::Normalizable(Normalizable { arguments, .. }) => arguments.get_mut(*index),
+            })
+    }
+
+    /// Replaces the subterm at the given position with `replacement`.
+    ///
+    /// Returns the replaced subterm, or `None` if the position does not exist in the term, in
+    /// which case the term is left unchanged.
+    pub fn replace_at(&mut self, position: &Position, replacement: Self) -> Option<Self> {
+        self.subterm_at_mut(position)
+            .map(|subterm| std::mem::replace(subterm, replacement))
+    }
+
+    /// Returns an iterator over all the subterms of the term together with their positions.
+    #[must_use]
+    pub fn subterms(&self) -> Subterms<'_, Literal> {
+        Subterms {
+            stack: vec![(Position::root(), self)],
+        }
+    }
+}
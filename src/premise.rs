@@ -1,10 +1,11 @@
 use std::collections::hash_map::Entry;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 use std::hash::Hash;
 
 use std::collections::{HashMap, HashSet};
 
-use crate::Term;
+use crate::term::HeadKind;
+use crate::{Folder, Normalizable, SymbolAxioms, Term};
 
 /// Represents a premise of equalities.
 ///
@@ -16,17 +17,20 @@ use crate::Term;
 /// z = y,
 /// ```
 ///
-/// Then the premise can be represented as
+/// With the `serde` feature, the premise is serialized as
 ///
 /// ```json
-/// "Premise": {
-///    "equalities": {
-///         "x": ["y", "z"],
-///         "y": ["x", "z"],
-///         "z": ["y"]
-///     }
+/// {
+///     "equalities": [
+///         [{ "Literal": "x" }, { "Literal": "y" }],
+///         [{ "Literal": "x" }, { "Literal": "z" }],
+///         [{ "Literal": "y" }, { "Literal": "z" }]
+///     ],
+///     "normalizations": []
 /// }
 /// ```
+///
+/// where each equality is listed once, in sorted order.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Premise<Literal: Ord + Eq + Hash + Clone + Debug> {
     /// A map from a term to a set of terms that are equal to it.
@@ -34,6 +38,25 @@ pub struct Premise<Literal: Ord + Eq + Hash + Clone + Debug> {
 
     /// A map from a term to its normalization.
     normalizables: HashMap<Literal, Normalization<Literal>>,
+
+    /// A map from a function symbol to its axioms.
+    axioms: HashMap<Literal, SymbolAxioms<Literal>>,
+
+    /// A map from the head of a term to the terms in `equalities` with that head, see
+    /// [`Term::head`].
+    heads: HashMap<(HeadKind, Literal, usize), HashSet<Term<Literal>>>,
+
+    /// A map from a symbol to the terms in `equalities` that mention it, see
+    /// [`Term::symbol`].
+    symbols: HashMap<Literal, HashSet<Term<Literal>>>,
+
+    /// A map from a term in `equalities` to its [canonical form](Premise::canonicalize), if
+    /// the term is not canonical already.
+    canonical_forms: HashMap<Term<Literal>, Term<Literal>>,
+
+    /// A map from the kind and symbol of a canonical form to the terms in `equalities` with
+    /// that canonical form.
+    canonical_heads: HashMap<(HeadKind, Literal), HashSet<Term<Literal>>>,
 }
 
 /// Represents a normalization symbol.
@@ -41,6 +64,7 @@ pub struct Premise<Literal: Ord + Eq + Hash + Clone + Debug> {
 /// This is used to represent something similar to `type alias` in the programming language
 /// construct.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Normalization<Literal: Ord + Eq + Hash + Clone + Debug> {
     /// The parameters of the normalization.
     pub parameters: Vec<Literal>,
@@ -50,18 +74,162 @@ pub struct Normalization<Literal: Ord + Eq + Hash + Clone + Debug> {
 }
 
 impl<Literal: Ord + Eq + Hash + Clone + Debug> Normalization<Literal> {
-    /// Returns the equivalence of the normalization.
-    pub fn equivalence(&self, arguments: &[Term<Literal>]) -> Option<Term<Literal>> {
+    /// Returns the equivalence of the normalization with the parameters substituted by the
+    /// arguments.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NormalizationError::ArityMismatch`] if the number of arguments differs from
+    /// the number of parameters.
+    pub fn equivalence(
+        &self,
+        arguments: &[Term<Literal>],
+    ) -> Result<Term<Literal>, NormalizationError<Literal>> {
         if self.parameters.len() != arguments.len() {
+            return Err(NormalizationError::ArityMismatch {
+                expected: self.parameters.len(),
+                found: arguments.len(),
+            });
+        }
+
+        // the parameters are substituted simultaneously, so that an argument mentioning another
+        // parameter is not substituted again
+        let mut substitution = Substitution {
+            parameters: self.parameters.iter().zip(arguments).collect(),
+        };
+
+        Ok(self.equivalence.clone().fold(&mut substitution))
+    }
+
+    /// Returns the equivalence with the parameters renamed to the given literals.
+    ///
+    /// Returns `None` if the number of literals differs from the number of parameters, or if a
+    /// literal of the equivalence that is not a parameter would be captured by the renaming.
+    pub(crate) fn renamed(&self, parameters: &[Literal]) -> Option<Term<Literal>> {
+        let captured = any_subterm(&self.equivalence, &|term| {
+            matches!(
+                term,
+                Term::Literal(literal)
+                    if parameters.contains(literal) && !self.parameters.contains(literal)
+            )
+        });
+        if captured {
             return None;
         }
-        let mut equivalence = self.equivalence.clone();
 
-        for (from, to) in self.parameters.iter().zip(arguments.iter()) {
-            equivalence.apply(&Term::Literal(from.clone()), to);
+        let arguments = parameters
+            .iter()
+            .cloned()
+            .map(Term::Literal)
+            .collect::<Vec<_>>();
+
+        self.equivalence(&arguments).ok()
+    }
+}
+
+/// An error produced when registering or expanding a normalization, see
+/// [`Premise::insert_normalization`] and [`Normalization::equivalence`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NormalizationError<Literal: Ord + Eq + Hash + Clone + Debug> {
+    /// The symbol already has a normalization.
+    DuplicateDefinition(Literal),
+
+    /// The same parameter is declared more than once.
+    DuplicateParameter {
+        /// The symbol of the normalization.
+        symbol: Literal,
+
+        /// The repeated parameter.
+        parameter: Literal,
+    },
+
+    /// A normalization was expanded with the wrong number of arguments.
+    ArityMismatch {
+        /// The number of parameters of the normalization.
+        expected: usize,
+
+        /// The number of arguments supplied.
+        found: usize,
+    },
+
+    /// The equivalence mentions the symbol being defined.
+    SelfReference(Literal),
+
+    /// A parameter does not appear in the equivalence, so the argument bound to it would be
+    /// discarded.
+    UnboundLiteral {
+        /// The symbol of the normalization.
+        symbol: Literal,
+
+        /// The parameter that does not appear.
+        parameter: Literal,
+    },
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + Display> Display for NormalizationError<Literal> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DuplicateDefinition(symbol) => {
+                write!(f, "normalization `{symbol}` is defined more than once")
+            }
+            Self::DuplicateParameter { symbol, parameter } => {
+                write!(
+                    f,
+                    "parameter `{parameter}` of normalization `{symbol}` is declared more than \
+                     once"
+                )
+            }
+            Self::ArityMismatch { expected, found } => {
+                write!(f, "expected {expected} arguments, found {found}")
+            }
+            Self::SelfReference(symbol) => {
+                write!(f, "normalization `{symbol}` refers to itself")
+            }
+            Self::UnboundLiteral { symbol, parameter } => {
+                write!(
+                    f,
+                    "parameter `{parameter}` of normalization `{symbol}` does not appear in its \
+                     equivalence"
+                )
+            }
         }
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + Display> std::error::Error
+    for NormalizationError<Literal>
+{
+}
+
+/// Returns the symbols of the term and its subterms, see [`Term::symbol`].
+fn symbols<Literal: Ord + Eq + Hash + Clone + Debug>(term: &Term<Literal>) -> HashSet<&Literal> {
+    term.subterms()
+        .map(|(_, subterm)| subterm.symbol())
+        .collect()
+}
+
+/// Determines if the term or any of its subterms satisfies the predicate.
+pub fn any_subterm<Literal: Ord + Eq + Hash + Clone + Debug>(
+    term: &Term<Literal>,
+    predicate: &impl Fn(&Term<Literal>) -> bool,
+) -> bool {
+    predicate(term)
+        || term
+            .arguments()
+            .unwrap_or_default()
+            .iter()
+            .any(|argument| any_subterm(argument, predicate))
+}
 
-        Some(equivalence)
+struct Substitution<'a, Literal: Ord + Eq + Hash + Clone + Debug> {
+    parameters: HashMap<&'a Literal, &'a Term<Literal>>,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Folder<Literal> for Substitution<'_, Literal> {
+    fn fold_literal(&mut self, literal: Literal) -> Term<Literal> {
+        self.parameters
+            .get(&literal)
+            .map_or_else(|| Term::Literal(literal), |&argument| argument.clone())
     }
 }
 
@@ -70,6 +238,11 @@ impl<Literal: Ord + Eq + Hash + Clone + Debug> Default for Premise<Literal> {
         Self {
             equalities: HashMap::default(),
             normalizables: HashMap::default(),
+            axioms: HashMap::default(),
+            heads: HashMap::default(),
+            symbols: HashMap::default(),
+            canonical_forms: HashMap::default(),
+            canonical_heads: HashMap::default(),
         }
     }
 }
@@ -81,6 +254,23 @@ impl<Literal: Ord + Eq + Hash + Clone + Debug> Premise<Literal> {
         &self.equalities
     }
 
+    /// Returns every equality in the premise once, with the smaller term first, in sorted
+    /// order.
+    ///
+    /// [`Premise::equalities`] stores each equality in both directions.
+    #[must_use]
+    pub fn sorted_equalities(&self) -> Vec<(&Term<Literal>, &Term<Literal>)> {
+        let mut equalities = self
+            .equalities
+            .iter()
+            .flat_map(|(lhs, rhs)| rhs.iter().map(move |rhs| (lhs, rhs)))
+            .filter(|(lhs, rhs)| lhs <= rhs)
+            .collect::<Vec<_>>();
+        equalities.sort();
+
+        equalities
+    }
+
     /// Creates a new premise with pre-defined equalities.
     pub fn new_with_equalities(
         terms: impl IntoIterator<Item = (Term<Literal>, Term<Literal>)>,
@@ -94,13 +284,172 @@ impl<Literal: Ord + Eq + Hash + Clone + Debug> Premise<Literal> {
         premise
     }
 
+    /// Creates a premise with the given equalities and the normalizations and axioms of this
+    /// premise.
+    pub(crate) fn with_equalities(
+        &self,
+        equalities: impl IntoIterator<Item = (Term<Literal>, Term<Literal>)>,
+    ) -> Self {
+        let mut premise = Self::default();
+        premise.normalizables.clone_from(&self.normalizables);
+        premise.axioms.clone_from(&self.axioms);
+
+        // the canonical forms of the equalities depend on the axioms
+        for (term1, term2) in equalities {
+            premise.insert(term1, term2);
+        }
+
+        premise
+    }
+
     /// Inserts a new equality into the premise.
     pub fn insert(&mut self, term1: Term<Literal>, term2: Term<Literal>) {
-        self.equalities
-            .entry(term1.clone())
+        self.insert_entry(term1.clone(), term2.clone());
+        self.insert_entry(term2, term1);
+    }
+
+    fn insert_entry(&mut self, from: Term<Literal>, to: Term<Literal>) {
+        if !self.equalities.contains_key(&from) {
+            let (kind, symbol, arity) = from.head();
+            self.heads
+                .entry((kind, symbol.clone(), arity))
+                .or_default()
+                .insert(from.clone());
+
+            for symbol in symbols(&from) {
+                self.symbols
+                    .entry(symbol.clone())
+                    .or_default()
+                    .insert(from.clone());
+            }
+
+            self.insert_canonical_form(&from);
+        }
+
+        self.equalities.entry(from).or_default().insert(to);
+    }
+
+    /// Removes an equality from the premise.
+    ///
+    /// The equality is removed in both directions, as [`Premise::insert`] stores it in both
+    /// directions, and terms left without equalities are dropped. The premise then compares
+    /// equal to one that the equality was never inserted into.
+    ///
+    /// The premise keeps no consequences of its equalities, so the consequences of the removed
+    /// equality no longer hold in any later query.
+    ///
+    /// Returns `true` if the equality was in the premise.
+    pub fn remove(&mut self, term1: &Term<Literal>, term2: &Term<Literal>) -> bool {
+        let removed = self.remove_entry(term1, term2);
+        self.remove_entry(term2, term1);
+
+        removed
+    }
+
+    fn remove_entry(&mut self, from: &Term<Literal>, to: &Term<Literal>) -> bool {
+        let Some(equalities) = self.equalities.get_mut(from) else {
+            return false;
+        };
+
+        let removed = equalities.remove(to);
+        if equalities.is_empty() {
+            self.equalities.remove(from);
+
+            let (kind, symbol, arity) = from.head();
+            let head = (kind, symbol.clone(), arity);
+            if let Some(terms) = self.heads.get_mut(&head) {
+                terms.remove(from);
+                if terms.is_empty() {
+                    self.heads.remove(&head);
+                }
+            }
+
+            for symbol in symbols(from) {
+                if let Some(terms) = self.symbols.get_mut(symbol) {
+                    terms.remove(from);
+                    if terms.is_empty() {
+                        self.symbols.remove(symbol);
+                    }
+                }
+            }
+
+            self.remove_canonical_form(from);
+        }
+
+        removed
+    }
+
+    /// Records the canonical form of a term with equalities in the premise.
+    fn insert_canonical_form(&mut self, term: &Term<Literal>) {
+        let canonical = self.canonicalize(term);
+
+        let (kind, symbol, _) = canonical.head();
+        self.canonical_heads
+            .entry((kind, symbol.clone()))
             .or_default()
-            .insert(term2.clone());
-        self.equalities.entry(term2).or_default().insert(term1);
+            .insert(term.clone());
+
+        if canonical != *term {
+            self.canonical_forms.insert(term.clone(), canonical);
+        }
+    }
+
+    fn remove_canonical_form(&mut self, term: &Term<Literal>) {
+        let canonical = self.canonical_forms.remove(term);
+
+        let (kind, symbol, _) = canonical.as_ref().unwrap_or(term).head();
+        let head = (kind, symbol.clone());
+        if let Some(terms) = self.canonical_heads.get_mut(&head) {
+            terms.remove(term);
+            if terms.is_empty() {
+                self.canonical_heads.remove(&head);
+            }
+        }
+    }
+
+    /// Returns the [canonical form](Premise::canonicalize) of a term with equalities in the
+    /// premise.
+    pub(crate) fn canonical_form<'a>(&'a self, term: &'a Term<Literal>) -> &'a Term<Literal> {
+        self.canonical_forms.get(term).unwrap_or(term)
+    }
+
+    /// Returns the terms with equalities in the premise whose canonical form has the given kind
+    /// and symbol, with any number of arguments, see [`Term::head`].
+    pub(crate) fn terms_with_canonical_head(
+        &self,
+        (kind, symbol): (HeadKind, &Literal),
+    ) -> impl Iterator<Item = &Term<Literal>> {
+        self.canonical_heads
+            .get(&(kind, symbol.clone()))
+            .into_iter()
+            .flatten()
+    }
+
+    /// Returns the terms with equalities in the premise that have the given head, see
+    /// [`Term::head`].
+    pub(crate) fn terms_with_head(
+        &self,
+        (kind, symbol, arity): (HeadKind, &Literal, usize),
+    ) -> impl Iterator<Item = &Term<Literal>> {
+        self.heads
+            .get(&(kind, symbol.clone(), arity))
+            .into_iter()
+            .flatten()
+    }
+
+    /// Returns the terms with equalities in the premise that mention the given symbol in any
+    /// of their subterms, see [`Term::symbol`].
+    pub(crate) fn terms_mentioning(
+        &self,
+        symbol: &Literal,
+    ) -> impl Iterator<Item = &Term<Literal>> {
+        self.symbols.get(symbol).into_iter().flatten()
+    }
+
+    /// Returns the normalizations in the premise.
+    #[must_use]
+    pub const fn normalizations(&self) -> &HashMap<Literal, Normalization<Literal>> {
+        &self.normalizables
     }
 
     /// Returns the normalization of a symbol.
@@ -110,24 +459,186 @@ impl<Literal: Ord + Eq + Hash + Clone + Debug> Premise<Literal> {
 
     /// Inserts a new normalization into the premise.
     ///
-    /// Returns `true` if the normalization is inserted.
+    /// # Errors
     ///
-    /// Returns `false` if the normalization already exists, nothing is inserted.
+    /// Nothing is inserted if the normalization is invalid:
+    ///
+    /// - [`NormalizationError::DuplicateDefinition`] if the symbol already has a normalization.
+    /// - [`NormalizationError::DuplicateParameter`] if a parameter is declared more than once.
+    /// - [`NormalizationError::SelfReference`] if the equivalence mentions the symbol itself.
+    ///   Normalizations may still refer to each other.
+    /// - [`NormalizationError::UnboundLiteral`] if a parameter does not appear in the
+    ///   equivalence.
     pub fn insert_normalization(
         &mut self,
         symbol: Literal,
         parameters: Vec<Literal>,
         equivalence: Term<Literal>,
-    ) -> bool {
-        match self.normalizables.entry(symbol) {
-            Entry::Vacant(entry) => {
-                entry.insert(Normalization {
-                    parameters,
-                    equivalence,
+    ) -> Result<(), NormalizationError<Literal>> {
+        let entry = match self.normalizables.entry(symbol) {
+            Entry::Vacant(entry) => entry,
+            Entry::Occupied(entry) => {
+                return Err(NormalizationError::DuplicateDefinition(entry.key().clone()));
+            }
+        };
+
+        for (index, parameter) in parameters.iter().enumerate() {
+            if parameters[..index].contains(parameter) {
+                return Err(NormalizationError::DuplicateParameter {
+                    symbol: entry.into_key(),
+                    parameter: parameter.clone(),
                 });
-                true
             }
-            Entry::Occupied(..) => false,
         }
+
+        let symbol = entry.key();
+        if any_subterm(
+            &equivalence,
+            &|term| matches!(term, Term::Normalizable(normalizable) if normalizable.symbol == *symbol),
+        ) {
+            return Err(NormalizationError::SelfReference(entry.into_key()));
+        }
+
+        if let Some(parameter) = parameters.iter().find(|parameter| {
+            !any_subterm(
+                &equivalence,
+                &|term| matches!(term, Term::Literal(literal) if literal == *parameter),
+            )
+        }) {
+            return Err(NormalizationError::UnboundLiteral {
+                symbol: entry.into_key(),
+                parameter: parameter.clone(),
+            });
+        }
+
+        entry.insert(Normalization {
+            parameters,
+            equivalence,
+        });
+        Ok(())
+    }
+
+    /// Removes the normalization of a symbol from the premise.
+    ///
+    /// Normalizable terms of the symbol are no longer unfolded, and other normalizations that
+    /// mention the symbol are kept.
+    ///
+    /// Returns the removed normalization, or `None` if the symbol has no normalization.
+    pub fn remove_normalization(&mut self, symbol: &Literal) -> Option<Normalization<Literal>> {
+        self.normalizables.remove(symbol)
+    }
+
+    /// Returns the axioms of the function symbols in the premise.
+    #[must_use]
+    pub const fn axioms(&self) -> &HashMap<Literal, SymbolAxioms<Literal>> {
+        &self.axioms
+    }
+
+    /// Returns the axioms of a function symbol.
+    pub fn get_axioms(&self, symbol: &Literal) -> Option<&SymbolAxioms<Literal>> {
+        self.axioms.get(symbol)
+    }
+
+    /// Declares the axioms of a function symbol.
+    ///
+    /// Returns `true` if the axioms are inserted.
+    ///
+    /// Returns `false` if the symbol already has axioms, nothing is inserted.
+    pub fn insert_axioms(&mut self, symbol: Literal, axioms: SymbolAxioms<Literal>) -> bool {
+        if self.axioms.contains_key(&symbol) {
+            return false;
+        }
+
+        // only the terms that mention the symbol change their canonical form
+        let terms = self.terms_mentioning(&symbol).cloned().collect::<Vec<_>>();
+        self.axioms.insert(symbol, axioms);
+        for term in &terms {
+            self.remove_canonical_form(term);
+            self.insert_canonical_form(term);
+        }
+
+        true
+    }
+
+    /// Unfolds every normalizable term that has a normalization, until none is left.
+    ///
+    /// The premise must not have a [recursive normalization](Self::recursive_normalization).
+    pub(crate) fn unfold(&self, term: &Term<Literal>) -> Term<Literal> {
+        term.clone().fold(&mut Unfolder {
+            normalizations: &self.normalizables,
+        })
+    }
+
+    /// Returns a symbol whose normalization refers back to itself through the normalizations
+    /// it mentions, if there is one.
+    pub(crate) fn recursive_normalization(&self) -> Option<&Literal> {
+        fn mentions<Literal: Ord + Eq + Hash + Clone + Debug>(
+            term: &Term<Literal>,
+            symbols: &mut Vec<Literal>,
+        ) {
+            if let Term::Normalizable(normalizable) = term {
+                symbols.push(normalizable.symbol.clone());
+            }
+            for argument in term.arguments().unwrap_or_default() {
+                mentions(argument, symbols);
+            }
+        }
+
+        fn cycle<'a, Literal: Ord + Eq + Hash + Clone + Debug>(
+            symbol: &'a Literal,
+            premise: &'a Premise<Literal>,
+            finished: &mut HashMap<&'a Literal, bool>,
+        ) -> Option<&'a Literal> {
+            match finished.get(symbol) {
+                Some(true) => return None,
+                Some(false) => return Some(symbol),
+                None => {
+                    finished.insert(symbol, false);
+                }
+            }
+
+            let mut symbols = Vec::new();
+            if let Some(normalization) = premise.get_normalization(symbol) {
+                mentions(&normalization.equivalence, &mut symbols);
+            }
+
+            for mentioned in symbols {
+                let Some((mentioned, _)) = premise.normalizables.get_key_value(&mentioned) else {
+                    continue;
+                };
+                if let Some(symbol) = cycle(mentioned, premise, finished) {
+                    return Some(symbol);
+                }
+            }
+
+            finished.insert(symbol, true);
+            None
+        }
+
+        let mut symbols = self.normalizables.keys().collect::<Vec<_>>();
+        symbols.sort();
+
+        let mut finished = HashMap::new();
+        symbols
+            .into_iter()
+            .find_map(|symbol| cycle(symbol, self, &mut finished))
+    }
+}
+
+/// Unfolds every normalizable term that has a normalization.
+pub struct Unfolder<'a, Literal: Ord + Eq + Hash + Clone + Debug> {
+    pub normalizations: &'a HashMap<Literal, Normalization<Literal>>,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Folder<Literal> for Unfolder<'_, Literal> {
+    fn fold_normalizable(&mut self, normalizable: Normalizable<Literal>) -> Term<Literal> {
+        let equivalence = self
+            .normalizations
+            .get(&normalizable.symbol)
+            .and_then(|normalization| normalization.equivalence(&normalizable.arguments).ok());
+
+        equivalence.map_or(Term::Normalizable(normalizable), |equivalence| {
+            equivalence.fold(self)
+        })
     }
 }
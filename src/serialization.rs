@@ -0,0 +1,111 @@
+//! The `serde` encoding of [`Premise`].
+//!
+//! The equalities are stored in a map keyed by [`Term`], which most formats cannot use as map
+//! keys. Therefore, a premise is encoded as a structure of two sorted lists instead:
+//!
+//! ```json
+//! {
+//!     "equalities": [[lhs, rhs], ...],
+//!     "normalizations": [{ "symbol": ..., "parameters": [...], "equivalence": ... }, ...],
+//!     "axioms": [[symbol, { "commutative": ..., ... }], ...]
+//! }
+//! ```
+//!
+//! Each equality is listed once even though it is stored in both directions. The `axioms` list
+//! is omitted if no symbol has axioms.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Premise, SymbolAxioms, Term};
+
+#[derive(Serialize)]
+struct NormalizationRef<'a, Literal: Ord + Eq + Hash + Clone + Debug> {
+    symbol: &'a Literal,
+    parameters: &'a [Literal],
+    equivalence: &'a Term<Literal>,
+}
+
+#[derive(Serialize)]
+struct PremiseRef<'a, Literal: Ord + Eq + Hash + Clone + Debug> {
+    equalities: Vec<(&'a Term<Literal>, &'a Term<Literal>)>,
+    normalizations: Vec<NormalizationRef<'a, Literal>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    axioms: Vec<(&'a Literal, &'a SymbolAxioms<Literal>)>,
+}
+
+#[derive(Deserialize)]
+struct NormalizationRepr<Literal: Ord + Eq + Hash + Clone + Debug> {
+    symbol: Literal,
+    parameters: Vec<Literal>,
+    equivalence: Term<Literal>,
+}
+
+#[derive(Deserialize)]
+struct PremiseRepr<Literal: Ord + Eq + Hash + Clone + Debug> {
+    equalities: Vec<(Term<Literal>, Term<Literal>)>,
+    normalizations: Vec<NormalizationRepr<Literal>>,
+    #[serde(default = "Vec::new")]
+    axioms: Vec<(Literal, SymbolAxioms<Literal>)>,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + Serialize> Serialize for Premise<Literal> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let equalities = self.sorted_equalities();
+
+        let mut normalizations = self
+            .normalizations()
+            .iter()
+            .map(|(symbol, normalization)| NormalizationRef {
+                symbol,
+                parameters: &normalization.parameters,
+                equivalence: &normalization.equivalence,
+            })
+            .collect::<Vec<_>>();
+        normalizations.sort_by_key(|normalization| normalization.symbol);
+
+        let mut axioms = self.axioms().iter().collect::<Vec<_>>();
+        axioms.sort_by_key(|(symbol, _)| *symbol);
+
+        PremiseRef {
+            equalities,
+            normalizations,
+            axioms,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, Literal: Ord + Eq + Hash + Clone + Debug + Deserialize<'de>> Deserialize<'de>
+    for Premise<Literal>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = PremiseRepr::deserialize(deserializer)?;
+        let mut premise = Self::new_with_equalities(repr.equalities);
+
+        for normalization in repr.normalizations {
+            if let Err(error) = premise.insert_normalization(
+                normalization.symbol.clone(),
+                normalization.parameters,
+                normalization.equivalence,
+            ) {
+                return Err(serde::de::Error::custom(format!(
+                    "normalization {:?} is invalid: {error:?}",
+                    normalization.symbol
+                )));
+            }
+        }
+
+        for (symbol, axioms) in repr.axioms {
+            if !premise.insert_axioms(symbol.clone(), axioms) {
+                return Err(serde::de::Error::custom(format!(
+                    "axioms of {symbol:?} are declared more than once"
+                )));
+            }
+        }
+
+        Ok(premise)
+    }
+}
@@ -0,0 +1,74 @@
+//! Restriction of premises to the part relevant to a query.
+
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::{Premise, Term};
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Premise<Literal> {
+    /// Returns the part of the premise that can be relevant to comparing the given terms.
+    ///
+    /// A symbol is relevant if it appears in one of the terms, or in a relevant equality,
+    /// normalization or axiom. An equality is relevant if either side mentions a relevant
+    /// symbol, and a normalization or axioms are relevant if their symbol is. Literals,
+    /// functions and normalizables are not told apart, so a literal `a` makes a function `a`
+    /// relevant too.
+    ///
+    /// The rest of the premise shares no symbol with the terms or the relevant part, so it
+    /// cannot take part in showing the terms equal. [`equals`](crate::equals) therefore only
+    /// searches the slice of the premise for its terms. The slice is found through an index of
+    /// the symbols that each term of the premise mentions, so it takes time in the size of the
+    /// slice rather than of the premise.
+    ///
+    /// # Panics
+    ///
+    /// Never panics in practice: the normalizations are copied from this premise, which has
+    /// already accepted them.
+    #[must_use]
+    pub fn slice(&self, terms: &[&Term<Literal>]) -> Self {
+        let mut slice = Self::default();
+        let mut relevant = HashSet::new();
+        let mut pending = terms.to_vec();
+
+        while let Some(term) = pending.pop() {
+            for (_, subterm) in term.subterms() {
+                let symbol = subterm.symbol();
+                if !relevant.insert(symbol) {
+                    continue;
+                }
+
+                for lhs in self.terms_mentioning(symbol) {
+                    for rhs in &self.equalities()[lhs] {
+                        if !slice
+                            .equalities()
+                            .get(lhs)
+                            .is_some_and(|equalities| equalities.contains(rhs))
+                        {
+                            slice.insert(lhs.clone(), rhs.clone());
+                            pending.extend([lhs, rhs]);
+                        }
+                    }
+                }
+
+                if let Some(normalization) = self.get_normalization(symbol) {
+                    slice
+                        .insert_normalization(
+                            symbol.clone(),
+                            normalization.parameters.clone(),
+                            normalization.equivalence.clone(),
+                        )
+                        .expect("the normalization is accepted by this premise");
+                    pending.push(&normalization.equivalence);
+                }
+
+                if let Some(axioms) = self.get_axioms(symbol) {
+                    slice.insert_axioms(symbol.clone(), axioms.clone());
+                    pending.extend(&axioms.identity);
+                }
+            }
+        }
+
+        slice
+    }
+}
@@ -0,0 +1,944 @@
+//! Import and export of SMT-LIB 2 scripts in the quantifier-free theory of uninterpreted
+//! functions.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::{Debug, Display, Write};
+use std::hash::Hash;
+
+use crate::{Function, Normalizable, NormalizationError, Premise, Term};
+
+/// A problem read from an SMT-LIB 2 script, see [`parse_smtlib`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SmtLibProblem {
+    /// The equalities and normalizations asserted by the script.
+    pub premise: Premise<String>,
+
+    /// The pairs of terms asserted to be distinct by the script.
+    ///
+    /// The script is unsatisfiable exactly when [`equals`](crate::equals) holds for at least
+    /// one of the pairs under the [`premise`](Self::premise).
+    pub queries: Vec<(Term<String>, Term<String>)>,
+}
+
+/// The reason why importing an SMT-LIB 2 script failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SmtLibErrorKind {
+    /// A character that cannot start any token was found.
+    UnexpectedCharacter(char),
+
+    /// The script ended inside a list, quoted symbol or string.
+    UnexpectedEndOfInput,
+
+    /// A closing parenthesis without a matching opening parenthesis was found.
+    UnbalancedParenthesis,
+
+    /// An expression does not have the shape required at its location.
+    Malformed(&'static str),
+
+    /// A command, sort or expression outside of the supported subset was found.
+    Unsupported(String),
+
+    /// A symbol was used without being declared or defined.
+    UndeclaredSymbol(String),
+
+    /// A symbol was declared or defined more than once.
+    DuplicateDeclaration(String),
+
+    /// A function was applied to the wrong number of arguments.
+    ArityMismatch {
+        /// The applied symbol.
+        symbol: String,
+
+        /// The number of parameters in the declaration of the symbol.
+        expected: usize,
+
+        /// The number of arguments supplied.
+        found: usize,
+    },
+
+    /// A definition was rejected by [`Premise::insert_normalization`].
+    InvalidDefinition(NormalizationError<String>),
+}
+
+impl Display for SmtLibErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedCharacter(character) => {
+                write!(f, "unexpected character `{character}`")
+            }
+            Self::UnexpectedEndOfInput => write!(f, "unexpected end of input"),
+            Self::UnbalancedParenthesis => write!(f, "unbalanced `)`"),
+            Self::Malformed(expected) => write!(f, "malformed expression, expected {expected}"),
+            Self::Unsupported(construct) => write!(f, "`{construct}` is not supported"),
+            Self::UndeclaredSymbol(symbol) => write!(f, "symbol `{symbol}` is not declared"),
+            Self::DuplicateDeclaration(symbol) => {
+                write!(f, "symbol `{symbol}` is declared more than once")
+            }
+            Self::ArityMismatch {
+                symbol,
+                expected,
+                found,
+            } => write!(
+                f,
+                "`{symbol}` expects {expected} argument(s) but {found} were supplied"
+            ),
+            Self::InvalidDefinition(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+/// An error produced when importing an SMT-LIB 2 script, see [`parse_smtlib`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SmtLibError {
+    /// The line where the error occurred, starting from 1.
+    pub line: usize,
+
+    /// The column where the error occurred, starting from 1.
+    pub column: usize,
+
+    /// The reason of the error.
+    pub kind: SmtLibErrorKind,
+}
+
+impl Display for SmtLibError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.kind)
+    }
+}
+
+impl std::error::Error for SmtLibError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Atom {
+    Symbol(String),
+    Keyword(String),
+    Constant(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ExpressionKind {
+    Atom(Atom),
+    List(Vec<Expression>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Expression {
+    kind: ExpressionKind,
+    line: usize,
+    column: usize,
+}
+
+impl Expression {
+    const fn error(&self, kind: SmtLibErrorKind) -> SmtLibError {
+        SmtLibError {
+            line: self.line,
+            column: self.column,
+            kind,
+        }
+    }
+
+    fn symbol(&self) -> Option<&str> {
+        match &self.kind {
+            ExpressionKind::Atom(Atom::Symbol(symbol)) => Some(symbol),
+            _ => None,
+        }
+    }
+
+    fn list(&self) -> Option<&[Self]> {
+        match &self.kind {
+            ExpressionKind::List(list) => Some(list),
+            ExpressionKind::Atom(_) => None,
+        }
+    }
+
+    /// Splits a list whose first element is a symbol into the symbol and the rest.
+    fn application(&self) -> Option<(&str, &[Self])> {
+        let (head, rest) = self.list()?.split_first()?;
+        Some((head.symbol()?, rest))
+    }
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            ExpressionKind::Atom(Atom::Symbol(symbol) | Atom::Constant(symbol)) => {
+                write!(f, "{symbol}")
+            }
+            ExpressionKind::Atom(Atom::Keyword(keyword)) => write!(f, ":{keyword}"),
+            ExpressionKind::List(list) => {
+                write!(f, "(")?;
+                for (index, expression) in list.iter().enumerate() {
+                    if index != 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{expression}")?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+fn is_symbol_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || "~!@$%^&*_-+=<>.?/".contains(character)
+}
+
+struct Reader<'a> {
+    characters: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl Reader<'_> {
+    fn next(&mut self) -> Option<char> {
+        let character = self.characters.next()?;
+        if character == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(character)
+    }
+
+    const fn error(&self, kind: SmtLibErrorKind) -> SmtLibError {
+        SmtLibError {
+            line: self.line,
+            column: self.column,
+            kind,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(&character) = self.characters.peek() {
+            if character == ';' {
+                while self.characters.peek().is_some_and(|&c| c != '\n') {
+                    self.next();
+                }
+            } else if character.is_whitespace() {
+                self.next();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn delimited(&mut self, delimiter: char) -> Result<String, SmtLibError> {
+        let mut content = String::new();
+        loop {
+            match self.next() {
+                Some(character) if character == delimiter => {
+                    // a doubled `"` is an escaped quote in string literals
+                    if delimiter == '"' && self.characters.peek() == Some(&'"') {
+                        self.next();
+                        content.push('"');
+                    } else {
+                        return Ok(content);
+                    }
+                }
+                Some(character) => content.push(character),
+                None => return Err(self.error(SmtLibErrorKind::UnexpectedEndOfInput)),
+            }
+        }
+    }
+
+    /// Reads the next expression, or returns `None` at the end of the input.
+    fn expression(&mut self) -> Result<Option<Expression>, SmtLibError> {
+        self.skip_whitespace();
+
+        let (line, column) = (self.line, self.column);
+        let Some(&character) = self.characters.peek() else {
+            return Ok(None);
+        };
+
+        let kind = match character {
+            '(' => {
+                self.next();
+                let mut list = Vec::new();
+                loop {
+                    self.skip_whitespace();
+                    match self.characters.peek() {
+                        Some(')') => {
+                            self.next();
+                            break;
+                        }
+                        Some(_) => list.push(self.expression()?.expect("input is not empty")),
+                        None => return Err(self.error(SmtLibErrorKind::UnexpectedEndOfInput)),
+                    }
+                }
+                ExpressionKind::List(list)
+            }
+            ')' => return Err(self.error(SmtLibErrorKind::UnbalancedParenthesis)),
+            '|' => {
+                self.next();
+                ExpressionKind::Atom(Atom::Symbol(self.delimited('|')?))
+            }
+            '"' => {
+                self.next();
+                ExpressionKind::Atom(Atom::Constant(format!("\"{}\"", self.delimited('"')?)))
+            }
+            ':' => {
+                self.next();
+                let mut keyword = String::new();
+                while let Some(character) = self.characters.next_if(|&c| is_symbol_character(c)) {
+                    self.column += 1;
+                    keyword.push(character);
+                }
+                ExpressionKind::Atom(Atom::Keyword(keyword))
+            }
+            character if is_symbol_character(character) => {
+                let mut symbol = String::new();
+                while let Some(character) = self.characters.next_if(|&c| is_symbol_character(c)) {
+                    self.column += 1;
+                    symbol.push(character);
+                }
+                if symbol.starts_with(|c: char| c.is_ascii_digit()) {
+                    ExpressionKind::Atom(Atom::Constant(symbol))
+                } else {
+                    ExpressionKind::Atom(Atom::Symbol(symbol))
+                }
+            }
+            character => return Err(self.error(SmtLibErrorKind::UnexpectedCharacter(character))),
+        };
+
+        Ok(Some(Expression { kind, line, column }))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Declaration {
+    Function(usize),
+
+    /// Whether each parameter of the definition occurs in its body.
+    Definition(Vec<bool>),
+}
+
+#[derive(Debug, Default)]
+struct Importer {
+    declarations: HashMap<String, Declaration>,
+    problem: SmtLibProblem,
+}
+
+impl Importer {
+    fn declare(
+        &mut self,
+        name: &Expression,
+        declaration: Declaration,
+    ) -> Result<String, SmtLibError> {
+        let symbol = name
+            .symbol()
+            .ok_or_else(|| name.error(SmtLibErrorKind::Malformed("a symbol")))?;
+
+        if self
+            .declarations
+            .insert(symbol.to_string(), declaration)
+            .is_some()
+        {
+            return Err(name.error(SmtLibErrorKind::DuplicateDeclaration(symbol.to_string())));
+        }
+
+        Ok(symbol.to_string())
+    }
+
+    fn check_sort(sort: &Expression) -> Result<(), SmtLibError> {
+        match sort.symbol() {
+            Some("Bool") => Err(sort.error(SmtLibErrorKind::Unsupported("Bool".to_string()))),
+            Some(_) => Ok(()),
+            None => Err(sort.error(SmtLibErrorKind::Unsupported(sort.to_string()))),
+        }
+    }
+
+    fn term(
+        &self,
+        expression: &Expression,
+        parameters: &[String],
+    ) -> Result<Term<String>, SmtLibError> {
+        let (symbol, arguments) = match &expression.kind {
+            ExpressionKind::Atom(Atom::Symbol(symbol)) => (symbol.as_str(), &[][..]),
+            ExpressionKind::List(_) => {
+                let (symbol, arguments) = expression
+                    .application()
+                    .ok_or_else(|| expression.error(SmtLibErrorKind::Malformed("a term")))?;
+
+                // annotations do not change the meaning of the term
+                if symbol == "!" {
+                    let annotated = arguments
+                        .first()
+                        .ok_or_else(|| expression.error(SmtLibErrorKind::Malformed("a term")))?;
+                    return self.term(annotated, parameters);
+                }
+
+                (symbol, arguments)
+            }
+            ExpressionKind::Atom(_) => {
+                return Err(expression.error(SmtLibErrorKind::Unsupported(expression.to_string())))
+            }
+        };
+
+        if arguments.is_empty() && parameters.iter().any(|parameter| parameter == symbol) {
+            return Ok(Term::Literal(symbol.to_string()));
+        }
+
+        let declaration = self.declarations.get(symbol).ok_or_else(|| {
+            if matches!(symbol, "let" | "ite" | "forall" | "exists" | "match") {
+                expression.error(SmtLibErrorKind::Unsupported(symbol.to_string()))
+            } else {
+                expression.error(SmtLibErrorKind::UndeclaredSymbol(symbol.to_string()))
+            }
+        })?;
+
+        let arity = match declaration {
+            Declaration::Function(arity) => *arity,
+            Declaration::Definition(used) => used.len(),
+        };
+        if arity != arguments.len() {
+            return Err(expression.error(SmtLibErrorKind::ArityMismatch {
+                symbol: symbol.to_string(),
+                expected: arity,
+                found: arguments.len(),
+            }));
+        }
+
+        let arguments = arguments
+            .iter()
+            .map(|argument| self.term(argument, parameters))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(match declaration {
+            Declaration::Function(0) => Term::Literal(symbol.to_string()),
+            Declaration::Function(_) => Term::Function(Function {
+                symbol: symbol.to_string(),
+                arguments,
+            }),
+            // the normalization has no parameters for the unused arguments
+            Declaration::Definition(used) => Term::Normalizable(Normalizable {
+                symbol: symbol.to_string(),
+                arguments: arguments
+                    .into_iter()
+                    .zip(used)
+                    .filter_map(|(argument, &used)| used.then_some(argument))
+                    .collect(),
+            }),
+        })
+    }
+
+    fn terms(&self, expressions: &[Expression]) -> Result<Vec<Term<String>>, SmtLibError> {
+        expressions
+            .iter()
+            .map(|expression| self.term(expression, &[]))
+            .collect()
+    }
+
+    fn assertion(&mut self, formula: &Expression, negated: bool) -> Result<(), SmtLibError> {
+        let unsupported = || formula.error(SmtLibErrorKind::Unsupported(formula.to_string()));
+
+        if formula.symbol() == Some(if negated { "false" } else { "true" }) {
+            return Ok(());
+        }
+
+        let (connective, operands) = formula.application().ok_or_else(unsupported)?;
+
+        match (connective, negated) {
+            ("not", _) => match operands {
+                [operand] => self.assertion(operand, !negated),
+                _ => Err(formula.error(SmtLibErrorKind::Malformed("a single operand"))),
+            },
+            ("and", false) => operands
+                .iter()
+                .try_for_each(|operand| self.assertion(operand, false)),
+            ("=" | "distinct", _) if operands.len() < 2 => {
+                Err(formula.error(SmtLibErrorKind::Malformed("at least two operands")))
+            }
+            ("=", false) => {
+                let terms = self.terms(operands)?;
+                for pair in terms.windows(2) {
+                    self.problem
+                        .premise
+                        .insert(pair[0].clone(), pair[1].clone());
+                }
+                Ok(())
+            }
+            ("distinct", true) if operands.len() == 2 => {
+                let terms = self.terms(operands)?;
+                self.problem
+                    .premise
+                    .insert(terms[0].clone(), terms[1].clone());
+                Ok(())
+            }
+            ("=", true) if operands.len() == 2 => {
+                let mut terms = self.terms(operands)?.into_iter();
+                let (lhs, rhs) = (terms.next().unwrap(), terms.next().unwrap());
+                self.problem.queries.push((lhs, rhs));
+                Ok(())
+            }
+            ("distinct", false) => {
+                let terms = self.terms(operands)?;
+                for (index, lhs) in terms.iter().enumerate() {
+                    for rhs in &terms[index + 1..] {
+                        self.problem.queries.push((lhs.clone(), rhs.clone()));
+                    }
+                }
+                Ok(())
+            }
+            _ => Err(unsupported()),
+        }
+    }
+
+    fn command(&mut self, command: &Expression) -> Result<(), SmtLibError> {
+        let (name, arguments) = command
+            .application()
+            .ok_or_else(|| command.error(SmtLibErrorKind::Malformed("a command")))?;
+
+        match (name, arguments) {
+            (
+                "set-logic" | "set-info" | "set-option" | "declare-sort" | "check-sat"
+                | "get-model" | "get-info" | "get-value" | "exit",
+                _,
+            ) => Ok(()),
+            ("declare-const", [symbol, sort]) => {
+                Self::check_sort(sort)?;
+                self.declare(symbol, Declaration::Function(0))?;
+                Ok(())
+            }
+            ("declare-fun", [symbol, domain, sort]) => {
+                let domain = domain
+                    .list()
+                    .ok_or_else(|| domain.error(SmtLibErrorKind::Malformed("a list of sorts")))?;
+                domain.iter().try_for_each(Self::check_sort)?;
+                Self::check_sort(sort)?;
+
+                self.declare(symbol, Declaration::Function(domain.len()))?;
+                Ok(())
+            }
+            ("define-fun", [symbol, parameters, sort, body]) => {
+                Self::check_sort(sort)?;
+
+                let parameters = parameters
+                    .list()
+                    .ok_or_else(|| {
+                        parameters.error(SmtLibErrorKind::Malformed("a list of sorted variables"))
+                    })?
+                    .iter()
+                    .map(|parameter| match parameter.list() {
+                        Some([name, sort]) => {
+                            Self::check_sort(sort)?;
+                            name.symbol()
+                                .map(ToString::to_string)
+                                .ok_or_else(|| name.error(SmtLibErrorKind::Malformed("a symbol")))
+                        }
+                        _ => Err(parameter.error(SmtLibErrorKind::Malformed("a sorted variable"))),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                // the definition is not in scope of its own body
+                let equivalence = self.term(body, &parameters)?;
+
+                // a normalization cannot have parameters that its equivalence does not use, and
+                // the body does not depend on them, so they are left out
+                let used = parameters
+                    .iter()
+                    .map(|parameter| {
+                        equivalence.subterms().any(|(_, subterm)| {
+                            matches!(subterm, Term::Literal(literal) if literal == parameter)
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                let parameters = parameters
+                    .into_iter()
+                    .zip(&used)
+                    .filter_map(|(parameter, &used)| used.then_some(parameter))
+                    .collect();
+
+                let name = self.declare(symbol, Declaration::Definition(used))?;
+                self.problem
+                    .premise
+                    .insert_normalization(name, parameters, equivalence)
+                    .map_err(|error| symbol.error(SmtLibErrorKind::InvalidDefinition(error)))?;
+
+                Ok(())
+            }
+            ("assert", [formula]) => self.assertion(formula, false),
+            ("declare-const" | "declare-fun" | "define-fun" | "assert", _) => {
+                Err(command.error(SmtLibErrorKind::Malformed("different arguments")))
+            }
+            (name, _) => Err(command.error(SmtLibErrorKind::Unsupported(name.to_string()))),
+        }
+    }
+}
+
+/// Imports an SMT-LIB 2 script in the quantifier-free theory of uninterpreted functions.
+///
+/// The supported commands and how they are imported are:
+///
+/// - `declare-fun` and `declare-const` declare a [`Term::Literal`] if nullary and a
+///   [`Term::Function`] otherwise. Sorts are not checked, except that the `Bool` sort is
+///   rejected.
+/// - `define-fun` adds a normalization to the premise, applications of the defined symbol are
+///   imported as [`Term::Normalizable`]. Parameters that the body does not use are left out of
+///   the normalization, along with the corresponding arguments of its applications.
+/// - `assert` of `=`, a conjunction of equalities or a negated `distinct` adds equalities to the
+///   premise.
+/// - `assert` of a negated `=` or of `distinct` adds a query to the problem.
+/// - `set-logic`, `set-info`, `set-option`, `declare-sort`, `check-sat`, `get-*` and `exit` are
+///   ignored.
+///
+/// # Errors
+///
+/// Returns [`SmtLibError`] if the script is malformed or uses anything outside of this subset,
+/// such as `push`, `let` or uninterpreted predicates.
+pub fn parse_smtlib(source: &str) -> Result<SmtLibProblem, SmtLibError> {
+    let mut reader = Reader {
+        characters: source.chars().peekable(),
+        line: 1,
+        column: 1,
+    };
+    let mut importer = Importer::default();
+
+    while let Some(command) = reader.expression()? {
+        importer.command(&command)?;
+    }
+
+    Ok(importer.problem)
+}
+
+/// The symbols that cannot be used as the name of a declared function.
+const RESERVED: &[&str] = &[
+    "_",
+    "!",
+    "as",
+    "let",
+    "exists",
+    "forall",
+    "match",
+    "par",
+    "BINARY",
+    "DECIMAL",
+    "HEXADECIMAL",
+    "NUMERAL",
+    "STRING",
+    "=",
+    "=>",
+    "and",
+    "or",
+    "xor",
+    "not",
+    "distinct",
+    "ite",
+    "true",
+    "false",
+];
+
+/// How a symbol of a term is declared in the exported script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum SymbolKind {
+    Constant,
+    Function(usize),
+
+    /// A normalizable applied to a number of arguments that no normalization accepts.
+    Uninterpreted(usize),
+    Definition,
+}
+
+struct Exporter<'a, Literal: Ord + Eq + Hash + Clone + Debug + Display> {
+    premise: &'a Premise<Literal>,
+    symbols: BTreeSet<(&'a Literal, SymbolKind)>,
+    names: HashMap<(&'a Literal, SymbolKind), String>,
+    used: HashSet<String>,
+}
+
+fn is_simple_symbol(symbol: &str) -> bool {
+    !symbol.is_empty()
+        && !symbol.starts_with(|c: char| c.is_ascii_digit() || c == '@' || c == '.')
+        && symbol.chars().all(is_symbol_character)
+}
+
+fn quote(symbol: &str) -> String {
+    if is_simple_symbol(symbol) {
+        symbol.to_string()
+    } else {
+        format!("|{symbol}|")
+    }
+}
+
+/// Returns a name based on `base` that is not in `used` and adds it to `used`.
+fn fresh(base: &str, used: &mut HashSet<String>) -> String {
+    // `|` and `\` cannot appear in quoted symbols
+    let base = base.replace(['|', '\\'], "_");
+
+    let name = if used.contains(&base) {
+        (1..=used.len())
+            .map(|suffix| format!("{base}@{suffix}"))
+            .find(|name| !used.contains(name))
+            .expect("at most `used.len()` suffixes are taken")
+    } else {
+        base
+    };
+
+    used.insert(name.clone());
+    quote(&name)
+}
+
+impl<'a, Literal: Ord + Eq + Hash + Clone + Debug + Display> Exporter<'a, Literal> {
+    fn is_defined(&self, normalizable: &Normalizable<Literal>) -> bool {
+        self.premise
+            .get_normalization(&normalizable.symbol)
+            .is_some_and(|normalization| {
+                normalization.parameters.len() == normalizable.arguments.len()
+            })
+    }
+
+    fn collect(&mut self, term: &'a Term<Literal>, parameters: &[Literal]) {
+        match term {
+            Term::Literal(literal) => {
+                if !parameters.contains(literal) {
+                    self.symbols.insert((literal, SymbolKind::Constant));
+                }
+            }
+            Term::Function(Function { symbol, arguments }) => {
+                self.symbols
+                    .insert((symbol, SymbolKind::Function(arguments.len())));
+            }
+            Term::Normalizable(normalizable) => {
+                let kind = if self.is_defined(normalizable) {
+                    SymbolKind::Definition
+                } else {
+                    SymbolKind::Uninterpreted(normalizable.arguments.len())
+                };
+                self.symbols.insert((&normalizable.symbol, kind));
+            }
+        }
+
+        for argument in term.arguments().unwrap_or_default() {
+            self.collect(argument, parameters);
+        }
+    }
+
+    fn render(&self, term: &Term<Literal>, parameters: &[(&Literal, String)]) -> String {
+        let (name, arguments) = match term {
+            Term::Literal(literal) => {
+                if let Some((_, name)) = parameters.iter().find(|(p, _)| *p == literal) {
+                    return name.clone();
+                }
+                (&self.names[&(literal, SymbolKind::Constant)], &[][..])
+            }
+            Term::Function(Function { symbol, arguments }) => (
+                &self.names[&(symbol, SymbolKind::Function(arguments.len()))],
+                arguments.as_slice(),
+            ),
+            Term::Normalizable(normalizable) => {
+                let kind = if self.is_defined(normalizable) {
+                    SymbolKind::Definition
+                } else {
+                    SymbolKind::Uninterpreted(normalizable.arguments.len())
+                };
+                (
+                    &self.names[&(&normalizable.symbol, kind)],
+                    normalizable.arguments.as_slice(),
+                )
+            }
+        };
+
+        if arguments.is_empty() {
+            return name.clone();
+        }
+
+        let mut rendered = format!("({name}");
+        for argument in arguments {
+            rendered.push(' ');
+            rendered.push_str(&self.render(argument, parameters));
+        }
+        rendered.push(')');
+        rendered
+    }
+
+    /// Orders the normalizations so that each one only uses the ones before it.
+    ///
+    /// Returns `None` if the normalizations are mutually recursive.
+    fn definition_order(&self) -> Option<Vec<&'a Literal>> {
+        fn visit<'a, Literal: Ord + Eq + Hash + Clone + Debug + Display>(
+            exporter: &Exporter<'a, Literal>,
+            symbol: &'a Literal,
+            state: &mut HashMap<&'a Literal, bool>,
+            order: &mut Vec<&'a Literal>,
+        ) -> Option<()> {
+            match state.get(symbol) {
+                Some(true) => return Some(()),
+                Some(false) => return None,
+                None => {}
+            }
+
+            state.insert(symbol, false);
+            let normalization = &exporter.premise.normalizations()[symbol];
+            for (_, subterm) in normalization.equivalence.subterms() {
+                if let Term::Normalizable(normalizable) = subterm {
+                    if exporter.is_defined(normalizable) {
+                        let (dependency, _) = exporter
+                            .premise
+                            .normalizations()
+                            .get_key_value(&normalizable.symbol)
+                            .expect("the normalization is defined");
+                        visit(exporter, dependency, state, order)?;
+                    }
+                }
+            }
+            state.insert(symbol, true);
+            order.push(symbol);
+
+            Some(())
+        }
+
+        let mut symbols = self.premise.normalizations().keys().collect::<Vec<_>>();
+        symbols.sort();
+
+        let mut state = HashMap::new();
+        let mut order = Vec::new();
+        for symbol in symbols {
+            visit(self, symbol, &mut state, &mut order)?;
+        }
+
+        Some(order)
+    }
+
+    fn signature(&self, symbol: &Literal) -> (String, Vec<(&'a Literal, String)>) {
+        let (symbol, normalization) = self
+            .premise
+            .normalizations()
+            .get_key_value(symbol)
+            .expect("the normalization is defined");
+
+        // parameters shadow the declared symbols, so they must not clash with them
+        let mut used = self.used.clone();
+        let parameters = normalization
+            .parameters
+            .iter()
+            .map(|parameter| (parameter, fresh(&parameter.to_string(), &mut used)))
+            .collect::<Vec<_>>();
+
+        let mut signature = format!("{} (", self.names[&(symbol, SymbolKind::Definition)]);
+        for (index, (_, name)) in parameters.iter().enumerate() {
+            if index != 0 {
+                signature.push(' ');
+            }
+            let _ = write!(signature, "({name} U)");
+        }
+        signature.push_str(") U");
+
+        (signature, parameters)
+    }
+}
+
+/// Exports a premise and the query `lhs = rhs` as an SMT-LIB 2 script.
+///
+/// All terms are of a single uninterpreted sort `U`. The equalities of the premise are asserted,
+/// the normalizations become `define-fun` (or `define-funs-rec` if they are mutually
+/// recursive), and the query is asserted negated before `(check-sat)`. Therefore, the script is
+/// unsatisfiable exactly when the premise entails the query.
+///
+/// Symbols are written using their [`Display`] implementation. Symbols whose names clash with
+/// each other or with SMT-LIB reserved words are renamed by appending `@` and a number.
+///
+/// The [axioms](crate::SymbolAxioms) of function symbols are not exported.
+#[must_use]
+pub fn to_smtlib<Literal: Ord + Eq + Hash + Clone + Debug + Display>(
+    premise: &Premise<Literal>,
+    lhs: &Term<Literal>,
+    rhs: &Term<Literal>,
+) -> String {
+    let mut exporter = Exporter {
+        premise,
+        symbols: BTreeSet::new(),
+        names: HashMap::new(),
+        used: RESERVED.iter().map(ToString::to_string).collect(),
+    };
+
+    let equalities = premise.sorted_equalities();
+
+    for (lhs, rhs) in &equalities {
+        exporter.collect(lhs, &[]);
+        exporter.collect(rhs, &[]);
+    }
+    for (symbol, normalization) in premise.normalizations() {
+        exporter.symbols.insert((symbol, SymbolKind::Definition));
+        exporter.collect(&normalization.equivalence, &normalization.parameters);
+    }
+    exporter.collect(lhs, &[]);
+    exporter.collect(rhs, &[]);
+
+    for &(symbol, kind) in &exporter.symbols {
+        let name = fresh(&symbol.to_string(), &mut exporter.used);
+        exporter.names.insert((symbol, kind), name);
+    }
+
+    let order = exporter.definition_order();
+    let mut script = String::new();
+
+    let _ = writeln!(
+        script,
+        "(set-logic {})",
+        if order.is_some() { "QF_UF" } else { "UF" }
+    );
+    let _ = writeln!(script, "(declare-sort U 0)");
+
+    for &(symbol, kind) in &exporter.symbols {
+        let name = &exporter.names[&(symbol, kind)];
+        let arity = match kind {
+            SymbolKind::Constant => 0,
+            SymbolKind::Function(arity) | SymbolKind::Uninterpreted(arity) => arity,
+            SymbolKind::Definition => continue,
+        };
+
+        let _ = writeln!(
+            script,
+            "(declare-fun {name} ({}) U)",
+            vec!["U"; arity].join(" ")
+        );
+    }
+
+    if let Some(order) = order {
+        for symbol in order {
+            let (signature, parameters) = exporter.signature(symbol);
+            let body = exporter.render(&premise.normalizations()[symbol].equivalence, &parameters);
+            let _ = writeln!(script, "(define-fun {signature} {body})");
+        }
+    } else {
+        let mut symbols = premise.normalizations().keys().collect::<Vec<_>>();
+        symbols.sort();
+
+        let (signatures, bodies): (Vec<_>, Vec<_>) = symbols
+            .into_iter()
+            .map(|symbol| {
+                let (signature, parameters) = exporter.signature(symbol);
+                let body =
+                    exporter.render(&premise.normalizations()[symbol].equivalence, &parameters);
+                (format!("({signature})"), body)
+            })
+            .unzip();
+
+        let _ = writeln!(
+            script,
+            "(define-funs-rec ({}) ({}))",
+            signatures.join(" "),
+            bodies.join(" ")
+        );
+    }
+
+    for (lhs, rhs) in equalities {
+        let _ = writeln!(
+            script,
+            "(assert (= {} {}))",
+            exporter.render(lhs, &[]),
+            exporter.render(rhs, &[])
+        );
+    }
+
+    let _ = writeln!(
+        script,
+        "(assert (not (= {} {})))",
+        exporter.render(lhs, &[]),
+        exporter.render(rhs, &[])
+    );
+    let _ = writeln!(script, "(check-sat)");
+    let _ = writeln!(script, "(exit)");
+
+    script
+}
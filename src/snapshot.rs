@@ -0,0 +1,497 @@
+//! A compact, versioned binary encoding of [`Premise`].
+//!
+//! A snapshot consists of:
+//!
+//! - the magic bytes `FOLQ` and the format version as a little-endian `u32`,
+//! - the table of distinct literals,
+//! - the table of distinct terms, where each term refers to its symbol in the literal table and
+//!   to its arguments in the term table, so repeated subterms are stored once,
+//! - the equalities and normalizations, referring to both tables,
+//! - since version 2, the axioms of function symbols, each with a byte of flags and the
+//!   identity if there is one,
+//! - a 64-bit FNV-1a checksum of everything before it.
+//!
+//! All counts and indices are encoded as unsigned LEB128 integers. Snapshots of all earlier
+//! versions can still be read.
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::io::{Read, Write};
+
+use crate::{Function, Normalizable, Premise, SymbolAxioms, Term};
+
+const MAGIC: &[u8; 4] = b"FOLQ";
+
+/// The version of the snapshot format written by [`Premise::write_to`].
+pub const SNAPSHOT_VERSION: u32 = 2;
+
+const LITERAL_TAG: u8 = 0;
+const FUNCTION_TAG: u8 = 1;
+const NORMALIZABLE_TAG: u8 = 2;
+
+const COMMUTATIVE_FLAG: u8 = 1;
+const ASSOCIATIVE_FLAG: u8 = 1 << 1;
+const IDEMPOTENT_FLAG: u8 = 1 << 2;
+const IDENTITY_FLAG: u8 = 1 << 3;
+
+/// A literal that can be stored in a snapshot, see [`Premise::write_to`].
+pub trait SnapshotLiteral: Sized {
+    /// Appends the encoding of the literal to `buffer`.
+    fn encode(&self, buffer: &mut Vec<u8>);
+
+    /// Decodes a literal from the bytes produced by [`SnapshotLiteral::encode`].
+    ///
+    /// Returns `None` if the bytes are not a valid encoding.
+    fn decode(bytes: &[u8]) -> Option<Self>;
+}
+
+impl SnapshotLiteral for String {
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(self.as_bytes());
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        Self::from_utf8(bytes.to_vec()).ok()
+    }
+}
+
+macro_rules! impl_snapshot_literal_for_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl SnapshotLiteral for $integer {
+                fn encode(&self, buffer: &mut Vec<u8>) {
+                    buffer.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn decode(bytes: &[u8]) -> Option<Self> {
+                    bytes.try_into().ok().map(Self::from_le_bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_snapshot_literal_for_integer!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl SnapshotLiteral for usize {
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        (*self as u64).encode(buffer);
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        u64::decode(bytes).and_then(|value| value.try_into().ok())
+    }
+}
+
+/// An error produced when writing or reading a snapshot.
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// The underlying writer or reader failed.
+    Io(std::io::Error),
+
+    /// The data does not start with the snapshot magic bytes.
+    InvalidMagic,
+
+    /// The snapshot was written in a format version that this version cannot read.
+    UnsupportedVersion(u32),
+
+    /// The checksum does not match the content of the snapshot.
+    ChecksumMismatch,
+
+    /// The content of the snapshot is inconsistent although its checksum matches.
+    Corrupted(&'static str),
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "{error}"),
+            Self::InvalidMagic => write!(f, "the data is not a premise snapshot"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "the snapshot has version {version} but only versions up to {SNAPSHOT_VERSION} \
+                 are supported"
+            ),
+            Self::ChecksumMismatch => write!(f, "the snapshot checksum does not match"),
+            Self::Corrupted(reason) => write!(f, "the snapshot is corrupted: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for SnapshotError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+fn checksum(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn write_unsigned(buffer: &mut Vec<u8>, mut value: usize) {
+    loop {
+        #[allow(clippy::cast_possible_truncation)]
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            buffer.push(byte);
+            return;
+        }
+        buffer.push(byte | 0x80);
+    }
+}
+
+struct Encoder<'a, Literal: Ord + Eq + Hash + Clone + Debug> {
+    literals: HashMap<&'a Literal, usize>,
+    literal_table: Vec<u8>,
+    terms: HashMap<&'a Term<Literal>, usize>,
+    term_table: Vec<u8>,
+}
+
+impl<'a, Literal: Ord + Eq + Hash + Clone + Debug + SnapshotLiteral> Encoder<'a, Literal> {
+    fn literal(&mut self, literal: &'a Literal) -> usize {
+        if let Some(index) = self.literals.get(literal) {
+            return *index;
+        }
+
+        let mut bytes = Vec::new();
+        literal.encode(&mut bytes);
+        write_unsigned(&mut self.literal_table, bytes.len());
+        self.literal_table.extend_from_slice(&bytes);
+
+        let index = self.literals.len();
+        self.literals.insert(literal, index);
+        index
+    }
+
+    fn term(&mut self, term: &'a Term<Literal>) -> usize {
+        if let Some(index) = self.terms.get(term) {
+            return *index;
+        }
+
+        let (tag, symbol, arguments) = match term {
+            Term::Literal(literal) => (LITERAL_TAG, literal, &[][..]),
+            Term::Function(Function { symbol, arguments }) => {
+                (FUNCTION_TAG, symbol, arguments.as_slice())
+            }
+            Term::Normalizable(Normalizable { symbol, arguments }) => {
+                (NORMALIZABLE_TAG, symbol, arguments.as_slice())
+            }
+        };
+
+        // arguments are encoded first so that a term only refers to terms before it
+        let arguments = arguments
+            .iter()
+            .map(|argument| self.term(argument))
+            .collect::<Vec<_>>();
+        let symbol = self.literal(symbol);
+
+        self.term_table.push(tag);
+        write_unsigned(&mut self.term_table, symbol);
+        if tag != LITERAL_TAG {
+            write_unsigned(&mut self.term_table, arguments.len());
+            for argument in arguments {
+                write_unsigned(&mut self.term_table, argument);
+            }
+        }
+
+        let index = self.terms.len();
+        self.terms.insert(term, index);
+        index
+    }
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    const fn bytes(&mut self, length: usize) -> Result<&'a [u8], SnapshotError> {
+        if length > self.bytes.len() {
+            return Err(SnapshotError::Corrupted("unexpected end of data"));
+        }
+
+        let (bytes, rest) = self.bytes.split_at(length);
+        self.bytes = rest;
+        Ok(bytes)
+    }
+
+    fn unsigned(&mut self) -> Result<usize, SnapshotError> {
+        let mut value = 0_usize;
+
+        for shift in (0..usize::BITS).step_by(7) {
+            let byte = self.bytes(1)?[0];
+            let bits = usize::from(byte & 0x7f);
+
+            if bits.checked_shl(shift).and_then(|b| b.checked_shr(shift)) != Some(bits) {
+                break;
+            }
+            value |= bits << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(SnapshotError::Corrupted("integer is too large"))
+    }
+
+    fn index(&mut self, length: usize) -> Result<usize, SnapshotError> {
+        let index = self.unsigned()?;
+        if index < length {
+            Ok(index)
+        } else {
+            Err(SnapshotError::Corrupted("index out of bounds"))
+        }
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + SnapshotLiteral> Premise<Literal> {
+    /// Writes a snapshot of the premise, see [`Premise::read_from`].
+    ///
+    /// Repeated literals and subterms are stored only once.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::Io`] if writing fails.
+    pub fn write_to(&self, mut writer: impl Write) -> Result<(), SnapshotError> {
+        let mut encoder = Encoder {
+            literals: HashMap::new(),
+            literal_table: Vec::new(),
+            terms: HashMap::new(),
+            term_table: Vec::new(),
+        };
+
+        // the entries are written in sorted order, so that equal premises give equal bytes
+        let sorted_equalities = self.sorted_equalities();
+
+        let mut equalities = Vec::new();
+        for (lhs, rhs) in sorted_equalities {
+            equalities.push((encoder.term(lhs), encoder.term(rhs)));
+        }
+
+        let mut sorted_normalizations = self.normalizations().iter().collect::<Vec<_>>();
+        sorted_normalizations.sort_by_key(|(symbol, _)| *symbol);
+
+        let mut normalizations = Vec::new();
+        for (symbol, normalization) in sorted_normalizations {
+            normalizations.push((
+                encoder.literal(symbol),
+                normalization
+                    .parameters
+                    .iter()
+                    .map(|parameter| encoder.literal(parameter))
+                    .collect::<Vec<_>>(),
+                encoder.term(&normalization.equivalence),
+            ));
+        }
+
+        let mut sorted_axioms = self.axioms().iter().collect::<Vec<_>>();
+        sorted_axioms.sort_by_key(|(symbol, _)| *symbol);
+
+        let mut axioms = Vec::new();
+        for (symbol, symbol_axioms) in sorted_axioms {
+            let mut flags = 0;
+            for (flag, set) in [
+                (COMMUTATIVE_FLAG, symbol_axioms.commutative),
+                (ASSOCIATIVE_FLAG, symbol_axioms.associative),
+                (IDEMPOTENT_FLAG, symbol_axioms.idempotent),
+                (IDENTITY_FLAG, symbol_axioms.identity.is_some()),
+            ] {
+                if set {
+                    flags |= flag;
+                }
+            }
+
+            axioms.push((
+                encoder.literal(symbol),
+                flags,
+                symbol_axioms
+                    .identity
+                    .as_ref()
+                    .map(|identity| encoder.term(identity)),
+            ));
+        }
+
+        let mut buffer = MAGIC.to_vec();
+        buffer.extend_from_slice(&SNAPSHOT_VERSION.to_le_bytes());
+
+        write_unsigned(&mut buffer, encoder.literals.len());
+        buffer.extend_from_slice(&encoder.literal_table);
+
+        write_unsigned(&mut buffer, encoder.terms.len());
+        buffer.extend_from_slice(&encoder.term_table);
+
+        write_unsigned(&mut buffer, equalities.len());
+        for (lhs, rhs) in equalities {
+            write_unsigned(&mut buffer, lhs);
+            write_unsigned(&mut buffer, rhs);
+        }
+
+        write_unsigned(&mut buffer, normalizations.len());
+        for (symbol, parameters, equivalence) in normalizations {
+            write_unsigned(&mut buffer, symbol);
+            write_unsigned(&mut buffer, parameters.len());
+            for parameter in parameters {
+                write_unsigned(&mut buffer, parameter);
+            }
+            write_unsigned(&mut buffer, equivalence);
+        }
+
+        write_unsigned(&mut buffer, axioms.len());
+        for (symbol, flags, identity) in axioms {
+            write_unsigned(&mut buffer, symbol);
+            buffer.push(flags);
+            if let Some(identity) = identity {
+                write_unsigned(&mut buffer, identity);
+            }
+        }
+
+        let checksum = checksum(&buffer);
+        buffer.extend_from_slice(&checksum.to_le_bytes());
+
+        writer.write_all(&buffer)?;
+        Ok(())
+    }
+
+    /// Reads a snapshot written by [`Premise::write_to`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError`] if reading fails, if the data is not a snapshot of a version up
+    /// to [`SNAPSHOT_VERSION`], or if the data is corrupted.
+    pub fn read_from(mut reader: impl Read) -> Result<Self, SnapshotError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        if !bytes.starts_with(MAGIC) {
+            return Err(SnapshotError::InvalidMagic);
+        }
+
+        let version = bytes
+            .get(4..8)
+            .and_then(|version| version.try_into().ok())
+            .map(u32::from_le_bytes)
+            .ok_or(SnapshotError::Corrupted("unexpected end of data"))?;
+        if version == 0 || version > SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+
+        let (content, expected) = bytes
+            .split_last_chunk::<8>()
+            .filter(|(content, _)| content.len() >= 8)
+            .ok_or(SnapshotError::Corrupted("unexpected end of data"))?;
+        if checksum(content) != u64::from_le_bytes(*expected) {
+            return Err(SnapshotError::ChecksumMismatch);
+        }
+
+        let mut decoder = Decoder {
+            bytes: &content[8..],
+        };
+
+        let mut literals = Vec::new();
+        for _ in 0..decoder.unsigned()? {
+            let length = decoder.unsigned()?;
+            literals.push(
+                Literal::decode(decoder.bytes(length)?)
+                    .ok_or(SnapshotError::Corrupted("invalid literal"))?,
+            );
+        }
+
+        let mut terms = Vec::<Term<Literal>>::new();
+        for _ in 0..decoder.unsigned()? {
+            let tag = decoder.bytes(1)?[0];
+            let symbol = literals[decoder.index(literals.len())?].clone();
+
+            let term = if tag == LITERAL_TAG {
+                Term::Literal(symbol)
+            } else {
+                let mut arguments = Vec::new();
+                for _ in 0..decoder.unsigned()? {
+                    arguments.push(terms[decoder.index(terms.len())?].clone());
+                }
+
+                match tag {
+                    FUNCTION_TAG => Term::Function(Function { symbol, arguments }),
+                    NORMALIZABLE_TAG => Term::Normalizable(Normalizable { symbol, arguments }),
+                    _ => return Err(SnapshotError::Corrupted("invalid term tag")),
+                }
+            };
+
+            terms.push(term);
+        }
+
+        let mut premise = Self::default();
+
+        for _ in 0..decoder.unsigned()? {
+            let lhs = terms[decoder.index(terms.len())?].clone();
+            let rhs = terms[decoder.index(terms.len())?].clone();
+            premise.insert(lhs, rhs);
+        }
+
+        for _ in 0..decoder.unsigned()? {
+            let symbol = literals[decoder.index(literals.len())?].clone();
+
+            let mut parameters = Vec::new();
+            for _ in 0..decoder.unsigned()? {
+                parameters.push(literals[decoder.index(literals.len())?].clone());
+            }
+
+            let equivalence = terms[decoder.index(terms.len())?].clone();
+
+            if premise
+                .insert_normalization(symbol, parameters, equivalence)
+                .is_err()
+            {
+                return Err(SnapshotError::Corrupted("invalid normalization"));
+            }
+        }
+
+        let axioms_count = if version >= 2 { decoder.unsigned()? } else { 0 };
+        for _ in 0..axioms_count {
+            let symbol = literals[decoder.index(literals.len())?].clone();
+            let flags = decoder.bytes(1)?[0];
+
+            if flags & !(COMMUTATIVE_FLAG | ASSOCIATIVE_FLAG | IDEMPOTENT_FLAG | IDENTITY_FLAG) != 0
+            {
+                return Err(SnapshotError::Corrupted("invalid axiom flags"));
+            }
+
+            let identity = if flags & IDENTITY_FLAG == 0 {
+                None
+            } else {
+                Some(terms[decoder.index(terms.len())?].clone())
+            };
+
+            let axioms = SymbolAxioms {
+                commutative: flags & COMMUTATIVE_FLAG != 0,
+                associative: flags & ASSOCIATIVE_FLAG != 0,
+                idempotent: flags & IDEMPOTENT_FLAG != 0,
+                identity,
+            };
+
+            if !premise.insert_axioms(symbol, axioms) {
+                return Err(SnapshotError::Corrupted("duplicate axioms"));
+            }
+        }
+
+        if !decoder.bytes.is_empty() {
+            return Err(SnapshotError::Corrupted("trailing data"));
+        }
+
+        Ok(premise)
+    }
+}
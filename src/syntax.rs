@@ -0,0 +1,525 @@
+//! The textual syntax of terms and premises, see [`parse_premise`] for the grammar.
+
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::str::FromStr;
+
+use crate::{Function, Normalizable, NormalizationError, Premise, SymbolAxioms, Term};
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + Display> Display for Function<Literal> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol)?;
+        write_arguments(f, &self.arguments)
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + Display> Display for Normalizable<Literal> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "@{}", self.symbol)?;
+        write_arguments(f, &self.arguments)
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + Display> Display for Term<Literal> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Literal(literal) => write!(f, "{literal}"),
+            Self::Function(function) => write!(f, "{function}"),
+            Self::Normalizable(normalizable) => write!(f, "{normalizable}"),
+        }
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug + Display> Display for Premise<Literal> {
+    /// Formats the premise as a sequence of statements, one per line.
+    ///
+    /// The statements are sorted so that the output is deterministic, and each equality is
+    /// written only once even though it is stored in both directions.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let equalities = self.sorted_equalities();
+
+        for (lhs, rhs) in equalities {
+            writeln!(f, "{lhs} = {rhs};")?;
+        }
+
+        let mut normalizations = self.normalizations().iter().collect::<Vec<_>>();
+        normalizations.sort_by_key(|(symbol, _)| *symbol);
+
+        for (symbol, normalization) in normalizations {
+            write!(f, "norm {symbol}(")?;
+            for (index, parameter) in normalization.parameters.iter().enumerate() {
+                if index != 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{parameter}")?;
+            }
+            writeln!(f, ") := {};", normalization.equivalence)?;
+        }
+
+        let mut axioms = self.axioms().iter().collect::<Vec<_>>();
+        axioms.sort_by_key(|(symbol, _)| *symbol);
+
+        for (symbol, axioms) in axioms {
+            let mut names = Vec::new();
+            if axioms.commutative {
+                names.push("commutative".to_string());
+            }
+            if axioms.associative {
+                names.push("associative".to_string());
+            }
+            if axioms.idempotent {
+                names.push("idempotent".to_string());
+            }
+            if let Some(identity) = &axioms.identity {
+                names.push(format!("identity = {identity}"));
+            }
+
+            writeln!(f, "axioms {symbol}: {};", names.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_arguments<Literal: Ord + Eq + Hash + Clone + Debug + Display>(
+    f: &mut std::fmt::Formatter<'_>,
+    arguments: &[Term<Literal>],
+) -> std::fmt::Result {
+    write!(f, "(")?;
+    for (index, argument) in arguments.iter().enumerate() {
+        if index != 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{argument}")?;
+    }
+    write!(f, ")")
+}
+
+/// The reason why parsing failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A character that cannot start any token was found.
+    UnexpectedCharacter(char),
+
+    /// A token other than the expected one was found.
+    UnexpectedToken {
+        /// The description of what was expected.
+        expected: &'static str,
+
+        /// The description of the token that was found.
+        found: String,
+    },
+
+    /// The same symbol was given more than one normalization.
+    DuplicateNormalization(String),
+
+    /// The same symbol was given axioms more than once.
+    DuplicateAxioms(String),
+
+    /// A normalization was rejected by [`Premise::insert_normalization`] for another reason
+    /// than being defined more than once.
+    InvalidNormalization(NormalizationError<String>),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedCharacter(character) => {
+                write!(f, "unexpected character `{character}`")
+            }
+            Self::UnexpectedToken { expected, found } => {
+                write!(f, "expected {expected}, found {found}")
+            }
+            Self::DuplicateNormalization(symbol) => {
+                write!(f, "normalization `{symbol}` is defined more than once")
+            }
+            Self::DuplicateAxioms(symbol) => {
+                write!(f, "axioms of `{symbol}` are declared more than once")
+            }
+            Self::InvalidNormalization(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+/// An error produced when parsing the textual syntax, see [`parse_premise`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line where the error occurred, starting from 1.
+    pub line: usize,
+
+    /// The column where the error occurred, starting from 1.
+    pub column: usize,
+
+    /// The reason of the error.
+    pub kind: ParseErrorKind,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.kind)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Identifier(String),
+    LeftParenthesis,
+    RightParenthesis,
+    Comma,
+    At,
+    Equals,
+    Define,
+    Colon,
+    Semicolon,
+    EndOfInput,
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Identifier(identifier) => write!(f, "`{identifier}`"),
+            Self::LeftParenthesis => write!(f, "`(`"),
+            Self::RightParenthesis => write!(f, "`)`"),
+            Self::Comma => write!(f, "`,`"),
+            Self::At => write!(f, "`@`"),
+            Self::Equals => write!(f, "`=`"),
+            Self::Define => write!(f, "`:=`"),
+            Self::Colon => write!(f, "`:`"),
+            Self::Semicolon => write!(f, "`;`"),
+            Self::EndOfInput => write!(f, "end of input"),
+        }
+    }
+}
+
+const fn is_identifier_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || matches!(character, '_' | '\'')
+}
+
+fn tokenize(source: &str) -> Result<Vec<(Token, usize, usize)>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut characters = source.chars().peekable();
+    let (mut line, mut column) = (1, 1);
+
+    while let Some(&character) = characters.peek() {
+        let (start_line, start_column) = (line, column);
+        characters.next();
+        column += 1;
+
+        let token = match character {
+            '\n' => {
+                line += 1;
+                column = 1;
+                continue;
+            }
+            character if character.is_whitespace() => continue,
+            '/' if characters.peek() == Some(&'/') => {
+                while characters.next_if(|&character| character != '\n').is_some() {}
+                continue;
+            }
+            '(' => Token::LeftParenthesis,
+            ')' => Token::RightParenthesis,
+            ',' => Token::Comma,
+            '@' => Token::At,
+            '=' => Token::Equals,
+            ';' => Token::Semicolon,
+            ':' if characters.peek() == Some(&'=') => {
+                characters.next();
+                column += 1;
+                Token::Define
+            }
+            ':' => Token::Colon,
+            character
+                if is_identifier_character(character)
+                    || (character == '-'
+                        && characters.peek().is_some_and(char::is_ascii_digit)) =>
+            {
+                let mut identifier = character.to_string();
+                while let Some(character) = characters.next_if(|&c| is_identifier_character(c)) {
+                    identifier.push(character);
+                    column += 1;
+                }
+                Token::Identifier(identifier)
+            }
+            character => {
+                return Err(ParseError {
+                    line: start_line,
+                    column: start_column,
+                    kind: ParseErrorKind::UnexpectedCharacter(character),
+                })
+            }
+        };
+
+        tokens.push((token, start_line, start_column));
+    }
+
+    tokens.push((Token::EndOfInput, line, column));
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize, usize)>,
+    cursor: usize,
+}
+
+impl Parser {
+    fn new(source: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            tokens: tokenize(source)?,
+            cursor: 0,
+        })
+    }
+
+    fn peek(&self, offset: usize) -> &Token {
+        let index = (self.cursor + offset).min(self.tokens.len() - 1);
+        &self.tokens[index].0
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        let (_, line, column) = self.tokens[self.cursor];
+        ParseError { line, column, kind }
+    }
+
+    fn unexpected(&self, expected: &'static str) -> ParseError {
+        self.error(ParseErrorKind::UnexpectedToken {
+            expected,
+            found: self.peek(0).to_string(),
+        })
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.peek(0).clone();
+        if token != Token::EndOfInput {
+            self.cursor += 1;
+        }
+        token
+    }
+
+    fn expect(&mut self, token: &Token, expected: &'static str) -> Result<(), ParseError> {
+        if self.peek(0) == token {
+            self.advance();
+            Ok(())
+        } else {
+            Err(self.unexpected(expected))
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String, ParseError> {
+        match self.peek(0) {
+            Token::Identifier(_) => {
+                let Token::Identifier(identifier) = self.advance() else {
+                    unreachable!()
+                };
+                Ok(identifier)
+            }
+            _ => Err(self.unexpected("an identifier")),
+        }
+    }
+
+    fn arguments(&mut self) -> Result<Vec<Term<String>>, ParseError> {
+        self.expect(&Token::LeftParenthesis, "`(`")?;
+
+        let mut arguments = Vec::new();
+        if self.peek(0) != &Token::RightParenthesis {
+            loop {
+                arguments.push(self.term()?);
+
+                if self.peek(0) == &Token::Comma {
+                    self.advance();
+                } else {
+                    break;
+                }
+            }
+        }
+
+        self.expect(&Token::RightParenthesis, "`,` or `)`")?;
+
+        Ok(arguments)
+    }
+
+    fn term(&mut self) -> Result<Term<String>, ParseError> {
+        if self.peek(0) == &Token::At {
+            self.advance();
+            let symbol = self.identifier()?;
+            let arguments = if self.peek(0) == &Token::LeftParenthesis {
+                self.arguments()?
+            } else {
+                Vec::new()
+            };
+
+            return Ok(Term::Normalizable(Normalizable { symbol, arguments }));
+        }
+
+        let symbol = self.identifier().map_err(|_| self.unexpected("a term"))?;
+
+        if self.peek(0) == &Token::LeftParenthesis {
+            Ok(Term::Function(Function {
+                symbol,
+                arguments: self.arguments()?,
+            }))
+        } else {
+            Ok(Term::Literal(symbol))
+        }
+    }
+
+    fn statement(&mut self, premise: &mut Premise<String>) -> Result<(), ParseError> {
+        if self.peek(0) == &Token::Identifier("norm".to_string())
+            && matches!(self.peek(1), Token::Identifier(_))
+        {
+            self.advance();
+
+            let symbol_cursor = self.cursor;
+            let symbol = self.identifier()?;
+
+            self.expect(&Token::LeftParenthesis, "`(`")?;
+            let mut parameters = Vec::new();
+            if self.peek(0) != &Token::RightParenthesis {
+                loop {
+                    parameters.push(self.identifier()?);
+
+                    if self.peek(0) == &Token::Comma {
+                        self.advance();
+                    } else {
+                        break;
+                    }
+                }
+            }
+            self.expect(&Token::RightParenthesis, "`,` or `)`")?;
+            self.expect(&Token::Define, "`:=`")?;
+
+            let equivalence = self.term()?;
+            self.expect(&Token::Semicolon, "`;`")?;
+
+            if let Err(error) = premise.insert_normalization(symbol, parameters, equivalence) {
+                self.cursor = symbol_cursor;
+                return Err(self.error(match error {
+                    NormalizationError::DuplicateDefinition(symbol) => {
+                        ParseErrorKind::DuplicateNormalization(symbol)
+                    }
+                    error => ParseErrorKind::InvalidNormalization(error),
+                }));
+            }
+        } else if self.peek(0) == &Token::Identifier("axioms".to_string())
+            && matches!(self.peek(1), Token::Identifier(_))
+        {
+            self.advance();
+
+            let symbol_cursor = self.cursor;
+            let symbol = self.identifier()?;
+            self.expect(&Token::Colon, "`:`")?;
+
+            let mut axioms = SymbolAxioms::default();
+            if self.peek(0) != &Token::Semicolon {
+                loop {
+                    match self
+                        .identifier()
+                        .map_err(|_| self.unexpected("an axiom"))?
+                        .as_str()
+                    {
+                        "commutative" => axioms.commutative = true,
+                        "associative" => axioms.associative = true,
+                        "idempotent" => axioms.idempotent = true,
+                        "identity" => {
+                            self.expect(&Token::Equals, "`=`")?;
+                            axioms.identity = Some(self.term()?);
+                        }
+                        _ => {
+                            self.cursor -= 1;
+                            return Err(self.unexpected("an axiom"));
+                        }
+                    }
+
+                    if self.peek(0) == &Token::Comma {
+                        self.advance();
+                    } else {
+                        break;
+                    }
+                }
+            }
+            self.expect(&Token::Semicolon, "`,` or `;`")?;
+
+            if !premise.insert_axioms(symbol.clone(), axioms) {
+                self.cursor = symbol_cursor;
+                return Err(self.error(ParseErrorKind::DuplicateAxioms(symbol)));
+            }
+        } else {
+            let lhs = self.term()?;
+            self.expect(&Token::Equals, "`=`")?;
+            let rhs = self.term()?;
+            self.expect(&Token::Semicolon, "`;`")?;
+
+            premise.insert(lhs, rhs);
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a term such as `f(x, @norm(y))`, see [`parse_premise`] for the syntax.
+///
+/// # Errors
+///
+/// Returns [`ParseError`] if the source is not a single well-formed term.
+pub fn parse_term(source: &str) -> Result<Term<String>, ParseError> {
+    let mut parser = Parser::new(source)?;
+    let term = parser.term()?;
+    parser.expect(&Token::EndOfInput, "end of input")?;
+
+    Ok(term)
+}
+
+/// Parses a premise.
+///
+/// Terms are written as follows:
+///
+/// ``` txt
+/// x               // a literal
+/// f(x, g(y))      // a function
+/// @norm(a)        // a normalizable, the parentheses may be omitted if there are no arguments
+/// ```
+///
+/// A premise is a sequence of statements, each terminated by a semicolon:
+///
+/// ``` txt
+/// x = f(y);                   // an equality
+/// norm N(a, b) := f(a, b);    // a normalization
+/// axioms U: commutative, associative, idempotent, identity = e;   // axioms of a symbol
+/// ```
+///
+/// Identifiers consist of ASCII letters, digits, `_` and `'`, and may start with `-` if it is
+/// followed by a digit, as in the integer `-1`. Line comments start with `//`.
+///
+/// The [`Display`] implementations of [`Term`] and [`Premise`] produce this syntax.
+///
+/// # Errors
+///
+/// Returns [`ParseError`] if the source is not a well-formed sequence of statements or if a
+/// symbol is given more than one normalization or more than one set of axioms.
+pub fn parse_premise(source: &str) -> Result<Premise<String>, ParseError> {
+    let mut parser = Parser::new(source)?;
+    let mut premise = Premise::default();
+
+    while parser.peek(0) != &Token::EndOfInput {
+        parser.statement(&mut premise)?;
+    }
+
+    Ok(premise)
+}
+
+impl FromStr for Term<String> {
+    type Err = ParseError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        parse_term(source)
+    }
+}
+
+impl FromStr for Premise<String> {
+    type Err = ParseError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        parse_premise(source)
+    }
+}
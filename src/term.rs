@@ -5,6 +5,7 @@ use std::hash::Hash;
 ///
 /// This represents something like `f(x, g(y))`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Function<Literal: Ord + Eq + Hash + Clone + Debug> {
     /// The name of the function.
     pub symbol: Literal,
@@ -15,6 +16,7 @@ pub struct Function<Literal: Ord + Eq + Hash + Clone + Debug> {
 
 /// Represents a term which can be normalized into another term without mapping equalities.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Normalizable<Literal: Ord + Eq + Hash + Clone + Debug> {
     /// The literal identifier.
     pub symbol: Literal,
@@ -25,9 +27,61 @@ pub struct Normalizable<Literal: Ord + Eq + Hash + Clone + Debug> {
 
 /// Represents a term used in equalities.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs)]
 pub enum Term<Literal: Ord + Eq + Hash + Clone + Debug> {
     Literal(Literal),
     Function(Function<Literal>),
     Normalizable(Normalizable<Literal>),
 }
+
+/// An equality between two terms.
+pub type Equality<Literal> = (Term<Literal>, Term<Literal>);
+
+/// The kind of a term, as part of its [head](Term::head).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum HeadKind {
+    Literal,
+    Function,
+    Normalizable,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Term<Literal> {
+    /// Returns the arguments of the term, or `None` if the term is a literal.
+    pub(crate) fn arguments(&self) -> Option<&[Self]> {
+        match self {
+            Self::Literal(_) => None,
+            Self::Function(Function { arguments, .. })
+            | Self::Normalizable(Normalizable { arguments, .. }) => Some(arguments),
+        }
+    }
+
+    /// Returns the kind of the term, its symbol and its number of arguments.
+    ///
+    /// Two terms can only be unified if they have the same head.
+    pub(crate) const fn head(&self) -> (HeadKind, &Literal, usize) {
+        match self {
+            Self::Literal(literal) => (HeadKind::Literal, literal, 0),
+            Self::Function(function) => (
+                HeadKind::Function,
+                &function.symbol,
+                function.arguments.len(),
+            ),
+            Self::Normalizable(normalizable) => (
+                HeadKind::Normalizable,
+                &normalizable.symbol,
+                normalizable.arguments.len(),
+            ),
+        }
+    }
+
+    /// Returns the literal itself, or the symbol of a function or normalizable term.
+    pub(crate) const fn symbol(&self) -> &Literal {
+        match self {
+            Self::Literal(literal) => literal,
+            Self::Function(Function { symbol, .. })
+            | Self::Normalizable(Normalizable { symbol, .. }) => symbol,
+        }
+    }
+}
@@ -7,11 +7,43 @@ use proptest::{
     test_runner::{Config, TestCaseError},
 };
 
-use crate::{equals, visitor::Visitor, Function, Premise, Term};
+use crate::{counterexample, equals, premise, term, Function, Premise, Term, Visitor};
+
+mod arithmetic;
+mod arrays;
+mod axioms;
+mod completion;
+mod dot;
+mod entailment;
+mod index;
+mod merge;
+mod minimize;
+mod model;
+mod normalize;
+mod position;
+mod registration;
+mod retraction;
+#[cfg(feature = "serde")]
+mod serialization;
+mod slice;
+mod smtlib;
+mod snapshot;
+mod syntax;
+mod theory;
+mod tptp;
+mod visitor;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ID(usize);
 
+/// Maps a symbol such as `x0` or `f12` to the identifier with its trailing number.
+pub fn id(symbol: &str) -> ID {
+    ID(symbol
+        .trim_start_matches(|character: char| character.is_ascii_alphabetic())
+        .parse()
+        .expect("symbol should end with a number"))
+}
+
 impl Arbitrary for ID {
     type Strategy = BoxedStrategy<Self>;
     type Parameters = ();
@@ -318,18 +350,20 @@ impl Property for Normalization {
             return false;
         }
 
-        premise.insert_normalization(self.literal_identifier, vec![self.normalizable_literal], {
-            let (lhs, rhs) = self.property.terms();
+        premise
+            .insert_normalization(self.literal_identifier, vec![self.normalizable_literal], {
+                let (lhs, rhs) = self.property.terms();
 
-            let mut normalized = if self.normalizable_at_lhs { lhs } else { rhs };
+                let mut normalized = if self.normalizable_at_lhs { lhs } else { rhs };
 
-            normalized.apply(
-                &self.substituted_term,
-                &Term::Literal(self.normalizable_literal),
-            );
+                normalized.apply(
+                    &self.substituted_term,
+                    &Term::Literal(self.normalizable_literal),
+                );
 
-            normalized
-        })
+                normalized
+            })
+            .is_ok()
     }
 }
 
@@ -365,6 +399,52 @@ proptest! {
     }
 }
 
+proptest! {
+    #![proptest_config(Config::with_cases(1000))]
+    #[test]
+    fn completion_testing(
+        property in Box::<dyn Property>::arbitrary()
+    ) {
+        let (term1, term2) = property.terms();
+        let mut premise = Premise::<ID>::default();
+
+        if property.requires_premise() && !property.apply(&mut premise) {
+            return Err(TestCaseError::reject("skip failed property application"))
+        }
+
+        // the rewrite system agrees with the premise it is completed from
+        if let Ok(system) = premise.complete() {
+            prop_assert!(system.equals(&term1, &term2));
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(Config::with_cases(1000))]
+    #[test]
+    fn counterexample_testing(
+        property in Box::<dyn Property>::arbitrary()
+    ) {
+        let (term1, term2) = property.terms();
+        let mut premise = Premise::<ID>::default();
+
+        if property.requires_premise() {
+            // without premise a model should tell the terms apart
+            let model = counterexample(&term1, &term2, &premise);
+            prop_assert!(
+                model.is_some_and(|model| model.evaluate(&term1) != model.evaluate(&term2))
+            );
+
+            if !property.apply(&mut premise) {
+                return Err(TestCaseError::reject("skip failed property application"))
+            }
+        }
+
+        // with the premise no model should
+        prop_assert!(counterexample(&term1, &term2, &premise).is_none());
+    }
+}
+
 #[test]
 fn reflixivity() {
     let term = Term::Literal(ID(0));
@@ -421,23 +501,14 @@ fn transitivity() {
 
 #[test]
 fn congruence() {
-    let term1 = Term::Function(Function {
-        symbol: ID(0),
-        arguments: vec![Term::Literal(ID(1)), Term::Literal(ID(2))],
-    });
-    let term2 = Term::Function(Function {
-        symbol: ID(0),
-        arguments: vec![Term::Literal(ID(3)), Term::Literal(ID(4))],
-    });
-    let not_equal = Term::Function(Function {
-        symbol: ID(0),
-        arguments: vec![Term::Literal(ID(5)), Term::Literal(ID(6))],
-    });
-
-    let mut premise = Premise::<ID>::default();
+    let term1 = term!([id] f0(x1, x2));
+    let term2 = term!([id] f0(x3, x4));
+    let not_equal = term!([id] f0(x5, x6));
 
-    premise.insert(Term::Literal(ID(1)), Term::Literal(ID(3)));
-    premise.insert(Term::Literal(ID(2)), Term::Literal(ID(4)));
+    let premise = premise! {[id]
+        x1 = x3;
+        x2 = x4;
+    };
 
     assert!(equals(&term1, &term2, &premise));
     assert!(equals(&term2, &term1, &premise));
@@ -451,32 +522,23 @@ fn congruence() {
 
 #[test]
 fn recursive_term() {
-    let premise = Premise::new_with_equalities([(
-        Term::Literal(ID(0)),
-        Term::Function(Function {
-            symbol: ID(0),
-            arguments: vec![Term::Literal(ID(0))],
-        }),
-    )]);
-
-    let lhs = Term::Function(Function {
-        symbol: ID(0),
-        arguments: vec![Term::Literal(ID(0))],
-    });
-    let rhs = Term::Function(Function {
-        symbol: ID(0),
-        arguments: vec![Term::Function(Function {
-            symbol: ID(0),
-            arguments: vec![Term::Function(Function {
-                symbol: ID(0),
-                arguments: vec![Term::Function(Function {
-                    symbol: ID(0),
-                    arguments: vec![Term::Literal(ID(0))],
-                })],
-            })],
-        })],
-    });
+    let premise = Premise::new_with_equalities([(term!([id] x0), term!([id] f0(x0)))]);
+
+    let lhs = term!([id] f0(x0));
+    let rhs = term!([id] f0(f0(f0(f0(x0)))));
 
     assert!(equals(&lhs, &rhs, &premise));
     assert!(equals(&rhs, &lhs, &premise));
 }
+
+#[test]
+fn normalization_macro() {
+    let premise = premise! {[id]
+        x0 = x1;
+        norm n2(x3) := f4(x3, x1);
+        n5() := @n2(x0);
+    };
+
+    assert!(equals(&term!([id] @n5), &term!([id] f4(x1, x0)), &premise));
+    assert!(!equals(&term!([id] @n5), &term!([id] f4(x1, x6)), &premise));
+}
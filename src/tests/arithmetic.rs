@@ -0,0 +1,78 @@
+use crate::{equals_modulo_arithmetic, parse_term, premise, term, Arithmetic, Premise, Term};
+
+fn arithmetic() -> Arithmetic<String> {
+    Arithmetic::new("add".to_string(), "mul".to_string())
+}
+
+#[test]
+fn canonicalize() {
+    let arithmetic = arithmetic();
+
+    assert_eq!(arithmetic.canonicalize(&term!(add(N, 1))), term!(add(N, 1)));
+    assert_eq!(arithmetic.canonicalize(&term!(add(1, N))), term!(add(N, 1)));
+    assert_eq!(
+        arithmetic.canonicalize(&term!(Array(T, add(2, 2)))),
+        term!(Array(T, 4))
+    );
+    assert_eq!(
+        arithmetic.canonicalize(&term!(mul(2, add(x, 1, mul(-1, x), y)))),
+        term!(add(mul(2, y), 2))
+    );
+    assert_eq!(
+        arithmetic.canonicalize(&term!(add(x, mul(-1, x)))),
+        term!(0)
+    );
+    assert_eq!(
+        parse_term("add(x, mul(-1, x))").unwrap(),
+        term!(add(x, mul(-1, x)))
+    );
+    assert_eq!(
+        arithmetic.canonicalize(&term!(mul(x, add(y, y)))),
+        term!(mul(x, mul(2, y)))
+    );
+}
+
+#[test]
+fn equality_modulo_arithmetic() {
+    let arithmetic = arithmetic();
+    let equal = |lhs: Term<String>, rhs: Term<String>, premise: &Premise<String>| {
+        equals_modulo_arithmetic(&lhs, &rhs, premise, &arithmetic)
+            && equals_modulo_arithmetic(&rhs, &lhs, premise, &arithmetic)
+    };
+
+    let empty = Premise::default();
+    assert!(equal(term!(add(N, 1)), term!(add(1, N)), &empty));
+    assert!(equal(
+        term!(Array(T, add(2, 2))),
+        term!(Array(T, 4)),
+        &empty
+    ));
+    assert!(!equal(term!(add(N, 1)), term!(N), &empty));
+    assert!(!equal(term!(f(x)), term!(f(y)), &empty));
+
+    // arithmetic facts propagate into uninterpreted functions
+    let premise = premise! {
+        add(x, y) = 5;
+        x = 2;
+        g(add(x, 1)) = c;
+    };
+    assert!(equal(term!(f(y)), term!(f(3)), &premise));
+    assert!(equal(term!(g(add(y, 0))), term!(c), &premise));
+    assert!(!equal(term!(f(y)), term!(f(x)), &premise));
+
+    // uninterpreted facts propagate into arithmetic
+    let premise = premise! {
+        f(a) = x;
+        f(b) = y;
+        a = b;
+        add(x, y) = 8;
+    };
+    assert!(equal(term!(Array(T, x)), term!(Array(T, 4)), &premise));
+
+    // inconsistent equations entail everything
+    let premise = premise! {
+        x = 1;
+        add(x, 1) = 3;
+    };
+    assert!(equal(term!(a), term!(b), &premise));
+}
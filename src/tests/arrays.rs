@@ -0,0 +1,126 @@
+use crate::{equals_with_theories, premise, term, Arrays, Premise, Term};
+
+fn arrays() -> Arrays<String> {
+    Arrays::new("select".to_string(), "store".to_string())
+        .with_distinct_keys([term!(name), term!(age)])
+}
+
+fn equal(lhs: &Term<String>, rhs: &Term<String>, premise: &Premise<String>) -> bool {
+    let arrays = arrays();
+
+    equals_with_theories(lhs, rhs, premise, &[&arrays])
+        && equals_with_theories(rhs, lhs, premise, &[&arrays])
+}
+
+#[test]
+fn read_over_write() {
+    let empty = Premise::default();
+
+    assert!(equal(&term!(select(store(m, k, v), k)), &term!(v), &empty));
+    assert!(equal(
+        &term!(f(select(store(r, age, Int), name))),
+        &term!(f(select(r, name))),
+        &empty
+    ));
+    assert!(!equal(&term!(select(store(m, k, v), j)), &term!(v), &empty));
+    assert!(!equal(
+        &term!(select(store(m, k, v), j)),
+        &term!(select(m, j)),
+        &empty
+    ));
+
+    // the keys are equal
+    let premise = premise! { k = j; };
+    assert!(equal(
+        &term!(select(store(m, k, v), j)),
+        &term!(v),
+        &premise
+    ));
+
+    // the keys are distinct
+    let premise = premise! {
+        k = name;
+        j = age;
+    };
+    assert!(equal(
+        &term!(select(store(store(m, k, v), name, w), j)),
+        &term!(select(m, age)),
+        &premise
+    ));
+
+    // the read is `v` whether the keys are equal or not
+    let premise = premise! { select(m, j) = v; };
+    assert!(equal(
+        &term!(select(store(m, k, v), j)),
+        &term!(v),
+        &premise
+    ));
+}
+
+#[test]
+fn extensionality() {
+    let empty = Premise::default();
+
+    assert!(equal(
+        &term!(store(store(m, k, v), k, w)),
+        &term!(store(m, k, w)),
+        &empty
+    ));
+    assert!(equal(&term!(store(m, k, select(m, k))), &term!(m), &empty));
+    assert!(equal(
+        &term!(store(store(r, name, S), age, Int)),
+        &term!(store(store(r, age, Int), name, S)),
+        &empty
+    ));
+    assert!(!equal(
+        &term!(store(store(m, k, v), j, w)),
+        &term!(store(store(m, j, w), k, v)),
+        &empty
+    ));
+}
+
+#[test]
+fn distinct_keys_conflict() {
+    let premise = premise! {
+        name = x;
+        x = age;
+    };
+
+    assert!(equal(&term!(a), &term!(b), &premise));
+}
+
+#[test]
+fn distinct_keys_checked_once() {
+    let arrays = Arrays::new("select".to_string(), "store".to_string())
+        .with_distinct_keys((0..30).map(|index| Term::Literal(format!("k{index}"))));
+    let premise = Premise::new_with_equalities((0..300).map(|index| {
+        (
+            Term::Literal(format!("x{index}")),
+            Term::Literal(format!("y{index}")),
+        )
+    }));
+
+    // the keys are compared once for the premise rather than once for each equality
+    assert!(equals_with_theories(
+        &term!(x7),
+        &term!(y7),
+        &premise,
+        &[&arrays]
+    ));
+    assert!(!equals_with_theories(
+        &term!(x7),
+        &term!(y8),
+        &premise,
+        &[&arrays]
+    ));
+
+    let mut conflicting = premise;
+    conflicting.insert(term!(k3), term!(x0));
+    conflicting.insert(term!(y0), term!(k4));
+    assert!(equals_with_theories(
+        &term!(x7),
+        &term!(y8),
+        &conflicting,
+        &[&arrays]
+    ));
+}
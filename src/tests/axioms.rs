@@ -0,0 +1,152 @@
+use crate::{equals, parse_premise, premise, term, Premise, SymbolAxioms};
+
+fn union_premise() -> Premise<String> {
+    let mut premise = premise! {
+        a = c;
+        U(b, a) = d;
+    };
+    premise.insert_axioms(
+        "U".to_string(),
+        SymbolAxioms {
+            idempotent: true,
+            identity: Some(term!(empty)),
+            ..SymbolAxioms::ac()
+        },
+    );
+    premise.insert_axioms("C".to_string(), SymbolAxioms::commutative());
+    premise.insert_axioms("A".to_string(), SymbolAxioms::associative());
+    premise
+}
+
+#[test]
+fn canonicalize() {
+    let premise = union_premise();
+
+    assert_eq!(
+        premise.canonicalize(&term!(U(b, U(empty, a), f(U(b, b))))),
+        term!(U(a, b, f(b)))
+    );
+    assert_eq!(premise.canonicalize(&term!(U(a, U(a, empty)))), term!(a));
+    assert_eq!(premise.canonicalize(&term!(U(empty, empty))), term!(empty));
+    assert_eq!(
+        premise.canonicalize(&term!(C(b, a, C(b)))),
+        term!(C(a, b, C(b)))
+    );
+    assert_eq!(
+        premise.canonicalize(&term!(A(A(b, a), a))),
+        term!(A(b, a, a))
+    );
+    assert_eq!(premise.canonicalize(&term!(g(b, a))), term!(g(b, a)));
+}
+
+#[test]
+fn equality_modulo_axioms() {
+    let premise = union_premise();
+    let equal = |lhs, rhs| equals(&lhs, &rhs, &premise) && equals(&rhs, &lhs, &premise);
+
+    assert!(equal(term!(U(a, U(b, a))), term!(U(U(empty, b), a))));
+    assert!(equal(term!(C(a, b)), term!(C(b, c))));
+    assert!(equal(term!(A(a, A(b, c))), term!(A(A(c, b), a))));
+    assert!(!equal(term!(A(a, b)), term!(A(b, a))));
+    assert!(!equal(term!(f(a, b)), term!(f(b, a))));
+
+    // the arguments of `U` are compared as sets
+    assert!(equal(term!(U(a, b)), term!(U(b, c, a))));
+    assert!(!equal(term!(U(a, b)), term!(U(a, e))));
+
+    // equalities of the premise are written in any form
+    assert!(equal(term!(U(a, b)), term!(d)));
+    assert!(equal(term!(f(U(c, b, empty))), term!(f(d))));
+}
+
+#[test]
+fn single_argument() {
+    let mut premise = union_premise();
+    premise.insert_axioms(
+        "I".to_string(),
+        SymbolAxioms {
+            identity: Some(term!(e)),
+            ..SymbolAxioms::default()
+        },
+    );
+    let equal = |lhs, rhs| equals(&lhs, &rhs, &premise) && equals(&rhs, &lhs, &premise);
+
+    assert_eq!(premise.canonicalize(&term!(U(a))), term!(a));
+    assert_eq!(premise.canonicalize(&term!(A(f(b)))), term!(f(b)));
+    assert_eq!(premise.canonicalize(&term!(I(b))), term!(b));
+    assert_eq!(premise.canonicalize(&term!(C(b))), term!(C(b)));
+
+    assert!(equal(term!(U(a)), term!(a)));
+    assert!(equal(term!(U(a)), term!(U(a, empty))));
+    assert!(equal(term!(I(a)), term!(I(a, e))));
+    assert!(equal(term!(f(A(b))), term!(f(b))));
+    assert!(!equal(term!(C(a)), term!(a)));
+}
+
+#[test]
+fn flattening_through_equalities() {
+    let mut premise = premise! {
+        d = U(a, b);
+        e = A(a, b);
+        x = y;
+        y = U(b, c);
+    };
+    premise.insert_axioms("U".to_string(), SymbolAxioms::ac());
+    premise.insert_axioms("A".to_string(), SymbolAxioms::associative());
+    let equal = |lhs, rhs| equals(&lhs, &rhs, &premise) && equals(&rhs, &lhs, &premise);
+
+    assert!(equal(term!(U(d, c)), term!(U(a, b, c))));
+    assert!(equal(term!(U(c, d)), term!(U(b, c, a))));
+    assert!(equal(term!(A(e, c)), term!(A(a, b, c))));
+    assert!(!equal(term!(A(c, e)), term!(A(a, b, c))));
+
+    // the argument only equals the application through another equality
+    assert!(equal(term!(U(a, x)), term!(U(a, b, c))));
+    assert!(!equal(term!(U(d, c)), term!(U(a, c))));
+
+    // expansions that refer back to themselves terminate
+    let mut cyclic = premise! {
+        d = U(a, e);
+        e = U(d, b);
+    };
+    cyclic.insert_axioms("U".to_string(), SymbolAxioms::ac());
+    assert!(equals(&term!(U(a, e)), &term!(U(a, U(d, b))), &cyclic));
+    assert!(!equals(&term!(U(d, c)), &term!(U(a, b, c, e)), &cyclic));
+}
+
+#[test]
+fn syntax() {
+    let premise = parse_premise(
+        "
+        axioms U: associative, commutative, idempotent, identity = empty;
+        axioms f:;
+        ",
+    )
+    .unwrap();
+
+    assert_eq!(
+        premise.get_axioms(&"U".to_string()),
+        union_premise().get_axioms(&"U".to_string())
+    );
+    assert_eq!(
+        premise.to_string(),
+        "axioms U: commutative, associative, idempotent, identity = empty;\naxioms f: ;\n"
+    );
+    assert_eq!(
+        premise.to_string().parse::<Premise<String>>().unwrap(),
+        premise
+    );
+
+    assert_eq!(
+        parse_premise("axioms U: commutative;\naxioms U: associative;")
+            .unwrap_err()
+            .to_string(),
+        "2:8: axioms of `U` are declared more than once"
+    );
+    assert_eq!(
+        parse_premise("axioms U: distributive;")
+            .unwrap_err()
+            .to_string(),
+        "1:11: expected an axiom, found `distributive`"
+    );
+}
@@ -0,0 +1,106 @@
+use std::cmp::Ordering;
+
+use crate::{
+    equals, premise, term, CompletionError, KnuthBendixOrdering, Premise, SymbolAxioms, Term,
+    TermOrdering,
+};
+
+#[test]
+fn agrees_with_equals() {
+    let premise = premise! {
+        a = f(b);
+        b = c;
+        g(a) = d;
+        f(f(c)) = h(a, b);
+        @N(c) = e;
+        norm N(x) := h(x, f(x));
+    };
+    let system = premise.complete().unwrap();
+
+    let terms = [
+        term!(a),
+        term!(b),
+        term!(c),
+        term!(d),
+        term!(e),
+        term!(f(c)),
+        term!(g(f(b))),
+        term!(f(a)),
+        term!(h(f(c), c)),
+        term!(@N(b)),
+        term!(@N(a)),
+        term!(k),
+    ];
+
+    for lhs in &terms {
+        for rhs in &terms {
+            assert_eq!(system.equals(lhs, rhs), equals(lhs, rhs, &premise));
+        }
+    }
+}
+
+#[test]
+fn reduced_rules() {
+    let premise = premise! {
+        f(c) = a;
+        c = b;
+        norm N(x) := g(x, x);
+    };
+    let system = premise.complete().unwrap();
+
+    assert_eq!(
+        system.to_string(),
+        "@N(x) -> g(x, x);\n\
+         c -> b;\n\
+         f(b) -> a;\n"
+    );
+
+    for (lhs, rhs) in system.rules() {
+        assert_eq!(system.normal_form(rhs), *rhs);
+        assert!(system
+            .rules()
+            .keys()
+            .all(|other| other == lhs || !lhs.to_string().contains(&other.to_string())));
+    }
+
+    assert_eq!(system.normal_form(&term!(@N(f(c)))), term!(g(a, a)));
+    assert_eq!(
+        KnuthBendixOrdering.compare(&term!(f(a)), &term!(g(a, a))),
+        Ordering::Less
+    );
+}
+
+/// Considers every pair of terms equal.
+#[derive(Debug)]
+struct Trivial;
+
+impl TermOrdering<String> for Trivial {
+    fn compare(&self, _: &Term<String>, _: &Term<String>) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+#[test]
+fn errors() {
+    let premise = premise! { a = b; };
+    assert_eq!(
+        premise.complete_with(&Trivial),
+        Err(CompletionError::Unorientable(term!(a), term!(b)))
+    );
+
+    let recursive = premise! {
+        norm List(x) := Cons(x, @Tail(x));
+        norm Tail(x) := @List(x);
+    };
+    assert_eq!(
+        recursive.complete(),
+        Err(CompletionError::RecursiveNormalization("List".to_string()))
+    );
+
+    let mut commutative = Premise::default();
+    commutative.insert_axioms("f".to_string(), SymbolAxioms::commutative());
+    assert_eq!(
+        commutative.complete().unwrap_err().to_string(),
+        "symbol `f` has axioms"
+    );
+}
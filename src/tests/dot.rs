@@ -0,0 +1,75 @@
+use crate::{premise, term, to_dot, Term};
+
+#[test]
+fn export() {
+    let premise = premise! {
+        a = f(b);
+        b = c;
+        g(@N(c)) = {Term::Literal("d\"".to_string())};
+        norm N(x) := h(x);
+    };
+
+    assert_eq!(
+        to_dot(&premise, &[term!(f(c))]),
+        r#"digraph premise {
+    node [shape=box];
+    subgraph cluster_0 {
+        label="class 0";
+        style=rounded;
+        n0 [label="a"];
+        n1 [label="f"];
+        n7 [label="f"];
+    }
+    subgraph cluster_1 {
+        label="class 1";
+        style=rounded;
+        n2 [label="b"];
+        n3 [label="c"];
+    }
+    subgraph cluster_2 {
+        label="class 2";
+        style=rounded;
+        n4 [label="d\""];
+        n5 [label="g"];
+    }
+    subgraph cluster_3 {
+        label="class 3";
+        style=rounded;
+        n6 [label="@N"];
+        n8 [label="h"];
+    }
+    n1 -> n2 [label="0"];
+    n6 -> n3 [label="0"];
+    n5 -> n6 [label="0"];
+    n7 -> n3 [label="0"];
+    n8 -> n3 [label="0"];
+    n0 -> n1 [dir=none, color=blue];
+    n2 -> n3 [dir=none, color=blue];
+    n4 -> n5 [dir=none, color=blue];
+    n6 -> n8 [style=dashed, label="unfold"];
+}
+"#
+    );
+}
+
+#[test]
+fn singleton_nodes() {
+    let premise = premise! { norm N() := @M; norm M() := @N; };
+
+    assert_eq!(
+        to_dot(&premise, &[term!(@N), term!(a)]),
+        "\
+digraph premise {
+    node [shape=box];
+    subgraph cluster_0 {
+        label=\"class 0\";
+        style=rounded;
+        n0 [label=\"@N\"];
+        n2 [label=\"@M\"];
+    }
+    n1 [label=\"a\"];
+    n0 -> n2 [style=dashed, label=\"unfold\"];
+}
+"
+    );
+}
@@ -0,0 +1,89 @@
+use crate::{equivalent, premise, Premise, SymbolAxioms};
+
+#[test]
+fn equalities() {
+    let stronger = premise! {
+        a = b;
+        b = c;
+        f(c) = d;
+    };
+    let weaker = premise! {
+        f(a) = d;
+        c = a;
+    };
+
+    assert!(stronger.entails(&weaker));
+    assert!(!weaker.entails(&stronger));
+    assert!(stronger.entails(&Premise::default()));
+    assert!(!Premise::default().entails(&weaker));
+
+    assert!(equivalent(
+        &stronger,
+        &premise! {
+            c = a;
+            b = a;
+            f(a) = d;
+        }
+    ));
+    assert!(!equivalent(&stronger, &weaker));
+}
+
+#[test]
+fn normalizations() {
+    let premise = premise! {
+        a = b;
+        norm N(x) := f(x, a);
+    };
+
+    // equal equivalences up to the names of the parameters
+    assert!(premise.entails(&premise! { norm N(y) := f(y, b); }));
+    assert!(!premise.entails(&premise! { norm N(y) := f(b, y); }));
+    assert!(!premise.entails(&premise! { norm N(x, y) := f(x, y); }));
+    assert!(!premise.entails(&premise! { norm M(x) := f(x, a); }));
+
+    // `x` is not a parameter of the other normalization, so it must not be captured
+    assert!(!premise! { norm N(x) := f(x, x); }.entails(&premise! { norm N(y) := f(y, x); }));
+
+    // the parameter is constrained by an equality
+    let constrained = premise! {
+        x = a;
+        norm N(x) := f(x, a);
+    };
+    assert!(!constrained.entails(&premise! { norm N(y) := f(y, y); }));
+    assert!(constrained.entails(&premise! { norm N(y) := f(y, a); }));
+
+    // the parameter is mentioned by another normalization
+    let mentioned = premise! {
+        norm N(x) := f(x, @M);
+        norm M() := x;
+    };
+    let stronger = premise! {
+        norm N(y) := f(y, y);
+        norm M() := x;
+    };
+    assert!(!mentioned.entails(&stronger));
+    assert!(mentioned.entails(&premise! {
+        norm N(y) := f(y, @M);
+        norm M() := x;
+    }));
+
+    // but not if it is a parameter there too
+    let bound = premise! {
+        norm N(x) := f(x, @M(x));
+        norm M(x) := g(x);
+    };
+    assert!(bound.entails(&premise! {
+        norm N(y) := f(y, g(y));
+        norm M(x) := g(x);
+    }));
+}
+
+#[test]
+fn axioms() {
+    let mut commutative = premise! { a = b; };
+    commutative.insert_axioms("f".to_string(), SymbolAxioms::commutative());
+
+    assert!(commutative.entails(&premise! { f(a, c) = f(c, b); }));
+    assert!(commutative.entails(&premise! { a = b; }));
+    assert!(!premise! { a = b; }.entails(&commutative));
+}
@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+use crate::term::HeadKind;
+use crate::{candidates, equals, premise, term, Function, Premise, SymbolAxioms, Term, Unfolding};
+
+fn heads(premise: &Premise<String>, symbol: &str, arity: usize) -> HashSet<Term<String>> {
+    premise
+        .terms_with_head((HeadKind::Function, &symbol.to_string(), arity))
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn maintained() {
+    let mut premise = premise! {
+        f(a) = b;
+        f(b) = g(a, b);
+        f(c) = f(a);
+    };
+
+    assert_eq!(
+        heads(&premise, "f", 1),
+        HashSet::from([term!(f(a)), term!(f(b)), term!(f(c))])
+    );
+    assert_eq!(heads(&premise, "g", 2), HashSet::from([term!(g(a, b))]));
+    assert!(heads(&premise, "g", 1).is_empty());
+
+    // `f(a)` keeps its equality with `b`
+    assert!(premise.remove(&term!(f(c)), &term!(f(a))));
+    assert_eq!(
+        heads(&premise, "f", 1),
+        HashSet::from([term!(f(a)), term!(f(b))])
+    );
+
+    assert!(premise.remove(&term!(f(b)), &term!(g(a, b))));
+    assert!(heads(&premise, "g", 2).is_empty());
+    assert_eq!(
+        premise,
+        premise! {
+            f(a) = b;
+        }
+    );
+}
+
+#[test]
+fn unfoldable_terms() {
+    let premise = premise! {
+        g(a) = b;
+        @N(b) = c;
+        norm N(x) := h(x);
+    };
+
+    // `@N(b)` has a different head, but is reached by unfolding it
+    assert!(equals(&term!(h(g(a))), &term!(c), &premise));
+    assert!(equals(&term!(k(h(b))), &term!(k(c)), &premise));
+    assert!(!equals(&term!(h(a)), &term!(c), &premise));
+}
+
+#[test]
+fn large_premise() {
+    let mut premise = Premise::default();
+    for index in 0..1000 {
+        premise.insert(
+            Term::Function(Function {
+                symbol: format!("f{index}"),
+                arguments: vec![term!(a)],
+            }),
+            Term::Literal(format!("x{index}")),
+        );
+    }
+    premise.insert(term!(g(a)), term!(b));
+
+    assert!(equals(&term!(k(g(a))), &term!(k(b)), &premise));
+    assert!(equals(&term!(f999(a)), &term!(x999), &premise));
+    assert!(!equals(&term!(f999(a)), &term!(x998), &premise));
+}
+
+#[test]
+fn narrowed_scan() {
+    let mut premise = Premise::default();
+    for index in 0..10_000 {
+        premise.insert(
+            Term::Function(Function {
+                symbol: format!("f{index}"),
+                arguments: vec![term!(a)],
+            }),
+            Term::Literal(format!("x{index}")),
+        );
+    }
+
+    let scanned = |premise: &Premise<String>| {
+        candidates(&term!(f42(a)), &term!(x7), premise, &[&Unfolding])
+            .into_iter()
+            .cloned()
+            .collect::<HashSet<_>>()
+    };
+
+    // only the terms with the head of either term are scanned
+    assert_eq!(scanned(&premise), HashSet::from([term!(f42(a)), term!(x7)]));
+    assert!(!equals(&term!(f42(a)), &term!(x7), &premise));
+    assert!(equals(&term!(f42(a)), &term!(x42), &premise));
+
+    // as well as the terms that can be unfolded
+    premise.insert(term!(@N(a)), term!(b));
+    premise
+        .insert_normalization("N".to_string(), vec!["x".to_string()], term!(h(x)))
+        .unwrap();
+    assert_eq!(
+        scanned(&premise),
+        HashSet::from([term!(f42(a)), term!(x7), term!(@N(a))])
+    );
+
+    // with axioms, the number of arguments of the canonical form may differ
+    premise.insert(term!(g(x7, g(b, c))), term!(d));
+    premise.insert_axioms("g".to_string(), SymbolAxioms::ac());
+    assert_eq!(
+        candidates(&term!(g(b, c, x7)), &term!(x7), &premise, &[&Unfolding])
+            .into_iter()
+            .cloned()
+            .collect::<HashSet<_>>(),
+        HashSet::from([term!(g(x7, g(b, c))), term!(x7), term!(@N(a))])
+    );
+}
+
+#[test]
+fn canonical_forms() {
+    let mut premise = Premise::default();
+    for index in 0..10_000 {
+        premise.insert(
+            Term::Function(Function {
+                symbol: format!("f{index}"),
+                arguments: vec![term!(a)],
+            }),
+            Term::Literal(format!("x{index}")),
+        );
+    }
+    premise.insert(term!(U(a, U(b, c))), term!(d));
+    premise.insert(term!(e), term!(U(a, b)));
+
+    let scanned = |premise: &Premise<String>| {
+        candidates(&term!(U(a, b, c)), &term!(d), premise, &[&Unfolding])
+            .into_iter()
+            .cloned()
+            .collect::<HashSet<_>>()
+    };
+
+    // the canonical forms follow axioms declared after the equalities
+    premise.insert_axioms("U".to_string(), SymbolAxioms::ac());
+    assert_eq!(
+        scanned(&premise),
+        HashSet::from([term!(U(a, U(b, c))), term!(U(a, b)), term!(d)])
+    );
+    assert!(equals(&term!(U(c, b, a)), &term!(d), &premise));
+    assert!(equals(&term!(U(e, c)), &term!(d), &premise));
+    assert!(!equals(&term!(U(a, c)), &term!(d), &premise));
+
+    assert!(premise.remove(&term!(U(a, U(b, c))), &term!(d)));
+    assert_eq!(scanned(&premise), HashSet::from([term!(U(a, b))]));
+    assert!(!equals(&term!(U(c, b, a)), &term!(d), &premise));
+}
@@ -0,0 +1,132 @@
+use crate::{
+    equals, equivalent, premise, term, MergeError, Normalization, NormalizationError, Premise,
+    SymbolAxioms,
+};
+
+#[test]
+fn union() {
+    let trait_premise = premise! {
+        a = b;
+        norm N(x) := f(x);
+    };
+    let impl_premise = premise! {
+        b = c;
+        norm N(y) := f(y);
+        norm M(y) := g(y);
+    };
+
+    let union = trait_premise.union(&impl_premise).unwrap();
+
+    assert!(equals(&term!(a), &term!(c), &union));
+    assert!(equals(&term!(@M(a)), &term!(g(c)), &union));
+    assert_eq!(
+        union.get_normalization(&"N".to_string()),
+        trait_premise.get_normalization(&"N".to_string())
+    );
+    assert!(union.entails(&trait_premise) && union.entails(&impl_premise));
+    assert_eq!(
+        trait_premise.union(&Premise::default()).unwrap(),
+        trait_premise
+    );
+}
+
+#[test]
+fn conflicts() {
+    let premise = premise! { norm N(x) := f(x, a); };
+
+    assert_eq!(
+        premise.union(&premise! { norm N(a) := f(a, a); }),
+        Err(MergeError::ConflictingNormalizations {
+            symbol: "N".to_string(),
+            existing: premise.get_normalization(&"N".to_string()).unwrap().clone(),
+            merged: Normalization {
+                parameters: vec!["a".to_string()],
+                equivalence: term!(f(a, a)),
+            },
+        })
+    );
+
+    let mut commutative = Premise::default();
+    commutative.insert_axioms("f".to_string(), SymbolAxioms::commutative());
+    let mut associative = Premise::default();
+    associative.insert_axioms("f".to_string(), SymbolAxioms::associative());
+
+    assert!(commutative.union(&commutative).is_ok());
+    assert_eq!(
+        commutative.union(&associative).unwrap_err().to_string(),
+        "the axioms of `f` disagree"
+    );
+
+    let mut premise = premise;
+    let error = premise
+        .extend_normalizations([
+            (
+                "M".to_string(),
+                Normalization {
+                    parameters: vec!["y".to_string()],
+                    equivalence: term!(g(y)),
+                },
+            ),
+            (
+                "K".to_string(),
+                Normalization {
+                    parameters: vec!["y".to_string()],
+                    equivalence: term!(@K(y)),
+                },
+            ),
+        ])
+        .unwrap_err();
+
+    assert_eq!(
+        error,
+        MergeError::InvalidNormalization(NormalizationError::SelfReference("K".to_string()))
+    );
+    assert!(premise.get_normalization(&"M".to_string()).is_some());
+}
+
+#[test]
+fn intersection() {
+    let premise1 = premise! {
+        a = x;
+        x = b;
+        f(c) = d;
+        norm N(x) := h(x);
+        norm M(x) := g(x);
+    };
+    let premise2 = premise! {
+        a = y;
+        y = b;
+        c = e;
+        norm N(y) := h(y);
+        norm M(x) := k(x);
+    };
+
+    let intersection = premise1.intersection(&premise2);
+
+    assert!(equals(&term!(a), &term!(b), &intersection));
+    assert!(!equals(&term!(a), &term!(x), &intersection));
+    assert!(!equals(&term!(c), &term!(e), &intersection));
+    assert!(!equals(&term!(f(c)), &term!(d), &intersection));
+    assert!(intersection.get_normalization(&"N".to_string()).is_some());
+    assert!(intersection.get_normalization(&"M".to_string()).is_none());
+
+    assert!(premise1.entails(&intersection) && premise2.entails(&intersection));
+    assert!(equivalent(&premise1.intersection(&premise1), &premise1));
+}
+
+#[test]
+fn extend() {
+    let mut premise = [(term!(a), term!(b)), (term!(b), term!(c))]
+        .into_iter()
+        .collect::<Premise<_>>();
+    premise.extend([(term!(c), term!(d)), (term!(a), term!(b))]);
+
+    assert_eq!(
+        premise,
+        premise! {
+            a = b;
+            b = c;
+            c = d;
+        }
+    );
+}
@@ -0,0 +1,97 @@
+use crate::{equivalent, premise, term, RemovalReason, RemovedEquality, SymbolAxioms};
+
+#[test]
+fn removes_redundant_equalities() {
+    let premise = premise! {
+        a = b;
+        b = c;
+        a = c;
+        d = d;
+        f(a) = f(c);
+        @N(a) = g(b);
+        norm N(x) := g(x);
+    };
+    let (minimized, removed) = premise.minimize();
+
+    assert_eq!(
+        minimized,
+        premise! {
+            b = c;
+            a = c;
+            norm N(x) := g(x);
+        }
+    );
+    assert!(equivalent(&premise, &minimized));
+
+    assert_eq!(
+        removed,
+        [
+            RemovedEquality {
+                equality: (term!(a), term!(b)),
+                reason: RemovalReason::Derivable {
+                    support: vec![(term!(a), term!(c)), (term!(b), term!(c))],
+                },
+            },
+            RemovedEquality {
+                equality: (term!(d), term!(d)),
+                reason: RemovalReason::Reflexive,
+            },
+            RemovedEquality {
+                equality: (term!(f(a)), term!(f(c))),
+                reason: RemovalReason::Derivable {
+                    support: vec![(term!(a), term!(c))],
+                },
+            },
+            RemovedEquality {
+                equality: (term!(g(b)), term!(@N(a))),
+                reason: RemovalReason::Derivable {
+                    support: vec![(term!(a), term!(c)), (term!(b), term!(c))],
+                },
+            },
+        ]
+    );
+}
+
+#[test]
+fn definitional_equalities() {
+    let premise = premise! {
+        @N(a) = g(a);
+        norm N(x) := g(x);
+    };
+    let (minimized, removed) = premise.minimize();
+
+    assert!(minimized.equalities().is_empty());
+    assert_eq!(
+        removed[0].reason,
+        RemovalReason::Derivable {
+            support: Vec::new()
+        }
+    );
+
+    // an irredundant premise is left as it is
+    let premise = premise! { a = b; f(a) = c; };
+    assert_eq!(premise.minimize(), (premise, Vec::new()));
+}
+
+#[test]
+fn explanation() {
+    let mut premise = premise! {
+        a = b;
+        c = d;
+        x = y;
+        f(b) = e;
+        norm N(p) := g(p);
+    };
+    premise.insert_axioms("h".to_string(), SymbolAxioms::commutative());
+
+    assert_eq!(
+        premise.explain(&term!(h(a, c)), &term!(h(d, b))),
+        Some(vec![(term!(a), term!(b)), (term!(c), term!(d))])
+    );
+    assert_eq!(
+        premise.explain(&term!(f(a)), &term!(e)),
+        Some(vec![(term!(a), term!(b)), (term!(e), term!(f(b)))])
+    );
+    assert_eq!(premise.explain(&term!(@N(x)), &term!(g(x))), Some(vec![]));
+    assert_eq!(premise.explain(&term!(a), &term!(c)), None);
+}
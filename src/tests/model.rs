@@ -0,0 +1,83 @@
+use crate::{counterexample, equals, premise, term, Premise, SymbolAxioms};
+
+#[test]
+fn distinguishes_terms() {
+    let premise = premise! {
+        a = f(b);
+        b = c;
+        g(a) = d;
+        @N(c) = e;
+        norm N(x) := h(x, x);
+    };
+
+    for (lhs, rhs) in [
+        (term!(f(c)), term!(d)),
+        (term!(g(f(b))), term!(a)),
+        (term!(e), term!(h(b, a))),
+        (term!(@N(a)), term!(@N(b))),
+        (term!(k), term!(a)),
+    ] {
+        assert!(!equals(&lhs, &rhs, &premise));
+
+        let model = counterexample(&lhs, &rhs, &premise).unwrap();
+        assert!(model.satisfies(&premise));
+        assert_ne!(model.evaluate(&lhs), model.evaluate(&rhs));
+    }
+}
+
+#[test]
+fn equal_terms() {
+    let premise = premise! {
+        a = f(b);
+        b = c;
+        @N(c) = e;
+        norm N(x) := h(x, x);
+    };
+
+    for (lhs, rhs) in [
+        (term!(f(c)), term!(a)),
+        (term!(g(f(b))), term!(g(a))),
+        (term!(e), term!(h(b, c))),
+        (term!(@N(f(c))), term!(h(a, f(b)))),
+    ] {
+        assert!(equals(&lhs, &rhs, &premise));
+        assert!(counterexample(&lhs, &rhs, &premise).is_none());
+    }
+}
+
+#[test]
+fn interpretation() {
+    let premise = premise! {
+        a = f(b);
+        b = c;
+    };
+    let model = counterexample(&term!(a), &term!(b), &premise).unwrap();
+
+    let a = model.evaluate(&term!(a));
+    let b = model.evaluate(&term!(b));
+
+    assert_eq!(model.size(), 3);
+    assert_eq!(model.literals()[&"c".to_string()], b);
+    assert_eq!(model.functions()[&"f".to_string()][&vec![b]], a);
+    assert_eq!(model.class(b), &[term!(b), term!(c)]);
+
+    // terms outside of the premise are interpreted as the default element
+    assert_eq!(model.evaluate(&term!(f(a))), model.default_element());
+    assert!(model.class(model.default_element()).is_empty());
+
+    // a model of one premise need not satisfy another
+    assert!(!model.satisfies(&premise! { a = b; }));
+}
+
+#[test]
+fn unsupported_premises() {
+    let recursive = premise! {
+        norm List(x) := Cons(x, @Tail(x));
+        norm Tail(x) := @List(x);
+    };
+    assert!(counterexample(&term!(a), &term!(b), &recursive).is_none());
+
+    let mut commutative = Premise::default();
+    commutative.insert_axioms("f".to_string(), SymbolAxioms::commutative());
+    assert!(counterexample(&term!(a), &term!(b), &commutative).is_none());
+}
@@ -0,0 +1,81 @@
+use crate::{premise, term, DepthLimitExceeded, Premise, Strategy, DEFAULT_DEPTH_LIMIT};
+
+fn premise() -> Premise<String> {
+    premise! {
+        a = b;
+        norm Pair(x, y) := Tuple(x, y);
+        norm Dup(x) := @Pair(x, x);
+        norm List(x) := Cons(x, @Tail(x));
+        norm Tail(x) := @List(x);
+    }
+}
+
+#[test]
+fn strategies() {
+    let premise = premise();
+    let term = term!(f(@Dup(@Pair(a, @Unknown(b))), @Pair(c)));
+
+    for strategy in [Strategy::Innermost, Strategy::Outermost] {
+        assert_eq!(
+            premise.normalize(&term, strategy).unwrap(),
+            term!(f(
+                Tuple(Tuple(a, @Unknown(b)), Tuple(a, @Unknown(b))),
+                @Pair(c)
+            ))
+        );
+    }
+
+    // only the outermost term is unfolded
+    assert_eq!(
+        premise
+            .normalize(&term!(@Dup(@Dup(a))), Strategy::Lazy)
+            .unwrap(),
+        term!(Tuple(@Dup(a), @Dup(a)))
+    );
+    assert_eq!(
+        premise.normalize(&term, Strategy::Lazy).unwrap(),
+        term.clone()
+    );
+}
+
+#[test]
+fn recursive_normalizations() {
+    let premise = premise();
+
+    assert_eq!(
+        premise.normalize(&term!(@List(a)), Strategy::Innermost),
+        Err(DepthLimitExceeded {
+            limit: DEFAULT_DEPTH_LIMIT,
+            term: term!(@List(a)),
+        })
+    );
+    assert_eq!(
+        premise.normalize(&term!(@List(a)), Strategy::Lazy).unwrap(),
+        term!(Cons(a, @Tail(a)))
+    );
+    assert!(premise
+        .normalize(&term!(f(b, @Tail(a))), Strategy::Outermost)
+        .is_err());
+}
+
+#[test]
+fn depth_limit() {
+    let premise = premise();
+    let term = term!(@Dup(a));
+
+    assert_eq!(
+        premise
+            .normalize_with_limit(&term, Strategy::Outermost, 2)
+            .unwrap(),
+        term!(Tuple(a, a))
+    );
+
+    let error = premise
+        .normalize_with_limit(&term, Strategy::Outermost, 1)
+        .unwrap_err();
+    assert_eq!(error.term, term!(@Pair(a, a)));
+    assert_eq!(
+        error.to_string(),
+        "unfolding `@Pair(a, a)` exceeds the limit of 1 nested unfoldings"
+    );
+}
@@ -0,0 +1,64 @@
+use super::{id, ID};
+use crate::{term, Position, Term};
+
+fn sample() -> Term<ID> {
+    term!([id] f0(x1, @n2(x3, x4)))
+}
+
+#[test]
+fn subterm_at() {
+    let term = sample();
+
+    assert_eq!(term.subterm_at(&Position::root()), Some(&term));
+    assert_eq!(
+        term.subterm_at(&Position::from(vec![1, 1])),
+        Some(&Term::Literal(ID(4)))
+    );
+    assert_eq!(term.subterm_at(&Position::from(vec![2])), None);
+    assert_eq!(term.subterm_at(&Position::from(vec![0, 0])), None);
+}
+
+#[test]
+fn replace_at() {
+    let mut term = sample();
+
+    assert_eq!(
+        term.replace_at(&Position::from(vec![1, 0]), Term::Literal(ID(5))),
+        Some(Term::Literal(ID(3)))
+    );
+    assert_eq!(
+        term.subterm_at(&Position::from(vec![1, 0])),
+        Some(&Term::Literal(ID(5)))
+    );
+
+    let before = term.clone();
+    assert_eq!(
+        term.replace_at(&Position::from(vec![0, 3]), Term::Literal(ID(6))),
+        None
+    );
+    assert_eq!(term, before);
+}
+
+#[test]
+fn subterms_in_pre_order() {
+    let term = sample();
+    let positions = term
+        .subterms()
+        .map(|(position, subterm)| {
+            assert_eq!(term.subterm_at(&position), Some(subterm));
+            position.to_string()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(positions, ["ε", "0", "1", "1.0", "1.1"]);
+}
+
+#[test]
+fn parent_and_child() {
+    let position = Position::root().child(1).child(0);
+
+    assert_eq!(position.indices(), &[1, 0]);
+    assert_eq!(position.parent(), Some(Position::from(vec![1])));
+    assert_eq!(Position::root().parent(), None);
+    assert!(position.parent().unwrap().parent().unwrap().is_root());
+}
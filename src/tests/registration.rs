@@ -0,0 +1,61 @@
+use crate::{premise, term, NormalizationError};
+
+#[test]
+fn invalid_normalizations() {
+    let mut premise = premise! {
+        norm N(x) := f(x);
+    };
+    let normalization = |parameters: &[&str], equivalence| {
+        (
+            parameters.iter().map(ToString::to_string).collect(),
+            equivalence,
+        )
+    };
+
+    for (symbol, (parameters, equivalence), error) in [
+        (
+            "N",
+            normalization(&["x"], term!(g(x))),
+            NormalizationError::DuplicateDefinition("N".to_string()),
+        ),
+        (
+            "M",
+            normalization(&["x", "y", "x"], term!(f(x, y))),
+            NormalizationError::DuplicateParameter {
+                symbol: "M".to_string(),
+                parameter: "x".to_string(),
+            },
+        ),
+        (
+            "M",
+            normalization(&["x"], term!(f(x, @M(x)))),
+            NormalizationError::SelfReference("M".to_string()),
+        ),
+        (
+            "M",
+            normalization(&["x", "y"], term!(f(y, @N(y)))),
+            NormalizationError::UnboundLiteral {
+                symbol: "M".to_string(),
+                parameter: "x".to_string(),
+            },
+        ),
+    ] {
+        assert_eq!(
+            premise.insert_normalization(symbol.to_string(), parameters, equivalence),
+            Err(error)
+        );
+    }
+
+    // nothing is inserted by a failed registration
+    assert_eq!(premise.normalizations().len(), 1);
+    assert_eq!(
+        premise
+            .get_normalization(&"N".to_string())
+            .unwrap()
+            .equivalence(&[]),
+        Err(NormalizationError::ArityMismatch {
+            expected: 1,
+            found: 0,
+        })
+    );
+}
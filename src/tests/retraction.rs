@@ -0,0 +1,37 @@
+use crate::{equals, premise, term, Normalization};
+
+#[test]
+fn retraction() {
+    let mut premise = premise! {
+        a = b;
+        b = c;
+        d = d;
+        f(b) = e;
+        norm N(x) := g(x);
+    };
+
+    assert!(equals(&term!(a), &term!(c), &premise));
+    assert!(premise.remove(&term!(c), &term!(b)));
+    assert!(!premise.remove(&term!(b), &term!(c)));
+    assert!(!equals(&term!(a), &term!(c), &premise));
+
+    assert!(premise.remove(&term!(d), &term!(d)));
+    assert!(equals(&term!(@N(a)), &term!(g(b)), &premise));
+    assert_eq!(
+        premise.remove_normalization(&"N".to_string()),
+        Some(Normalization {
+            parameters: vec!["x".to_string()],
+            equivalence: term!(g(x)),
+        })
+    );
+    assert_eq!(premise.remove_normalization(&"N".to_string()), None);
+    assert!(!equals(&term!(@N(a)), &term!(g(b)), &premise));
+
+    assert_eq!(
+        premise,
+        premise! {
+            a = b;
+            f(b) = e;
+        }
+    );
+}
@@ -0,0 +1,78 @@
+use crate::{premise, term, Premise, SymbolAxioms, Term};
+
+#[test]
+fn term_round_trip() {
+    let term = term!(f(x, @n(y)));
+    let json = serde_json::to_string(&term).unwrap();
+
+    assert_eq!(serde_json::from_str::<Term<String>>(&json).unwrap(), term);
+}
+
+#[test]
+fn premise_encoding() {
+    let premise = premise! {
+        y = x;
+        x = f(z);
+        norm n(a) := g(a, x);
+    };
+
+    let json = serde_json::to_value(&premise).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "equalities": [
+                [{ "Literal": "x" }, { "Literal": "y" }],
+                [
+                    { "Literal": "x" },
+                    { "Function": { "symbol": "f", "arguments": [{ "Literal": "z" }] } }
+                ],
+            ],
+            "normalizations": [{
+                "symbol": "n",
+                "parameters": ["a"],
+                "equivalence": {
+                    "Function": {
+                        "symbol": "g",
+                        "arguments": [{ "Literal": "a" }, { "Literal": "x" }]
+                    }
+                },
+            }],
+        })
+    );
+
+    assert_eq!(
+        serde_json::from_value::<Premise<String>>(json).unwrap(),
+        premise
+    );
+}
+
+#[test]
+fn duplicate_normalization() {
+    let json = r#"{
+        "equalities": [],
+        "normalizations": [
+            { "symbol": "n", "parameters": [], "equivalence": { "Literal": "a" } },
+            { "symbol": "n", "parameters": [], "equivalence": { "Literal": "b" } }
+        ]
+    }"#;
+
+    assert!(serde_json::from_str::<Premise<String>>(json).is_err());
+}
+
+#[test]
+fn axioms_round_trip() {
+    let mut premise = premise! { x = U(y, z); };
+    premise.insert_axioms(
+        "U".to_string(),
+        SymbolAxioms {
+            identity: Some(term!(e)),
+            ..SymbolAxioms::ac()
+        },
+    );
+
+    let json = serde_json::to_string(&premise).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Premise<String>>(&json).unwrap(),
+        premise
+    );
+}
@@ -0,0 +1,89 @@
+use crate::{equals, premise, term, Premise, SymbolAxioms};
+
+#[test]
+fn reachable_equalities() {
+    let premise = premise! {
+        a = f(b);
+        b = c;
+        c = g(d);
+        x = y;
+        h(y) = z;
+        @N(e) = k;
+        @M(y) = w;
+        norm N(p) := j(p, q);
+        norm M(p) := p;
+    };
+
+    assert_eq!(
+        premise.slice(&[&term!(a), &term!(@N(u))]),
+        premise! {
+            a = f(b);
+            b = c;
+            c = g(d);
+            @N(e) = k;
+            norm N(p) := j(p, q);
+        }
+    );
+    assert_eq!(
+        premise.slice(&[&term!(h(x))]),
+        premise! {
+            x = y;
+            h(y) = z;
+            @M(y) = w;
+            norm M(p) := p;
+        }
+    );
+    assert_eq!(premise.slice(&[&term!(v)]), Premise::default());
+}
+
+#[test]
+fn axioms() {
+    let mut premise = premise! {
+        e = c;
+        a = b;
+    };
+    premise.insert_axioms(
+        "f".to_string(),
+        SymbolAxioms {
+            identity: Some(term!(e)),
+            ..SymbolAxioms::default()
+        },
+    );
+
+    // the identity of a relevant symbol is relevant
+    let slice = premise.slice(&[&term!(f(a))]);
+    assert_eq!(
+        slice.get_axioms(&"f".to_string()),
+        premise.get_axioms(&"f".to_string())
+    );
+    assert_eq!(slice.equalities(), premise.equalities());
+    assert!(equals(&term!(f(b, e)), &term!(a), &slice));
+
+    let slice = premise.slice(&[&term!(g(a))]);
+    assert_eq!(slice.equalities(), premise! { a = b; }.equalities());
+    assert!(slice.axioms().is_empty());
+}
+
+#[test]
+fn removed_equalities() {
+    let mut premise = premise! {
+        a = f(b);
+        f(b) = c;
+        b = d;
+    };
+
+    // `f(b)` keeps its equality with `c`
+    assert!(premise.remove(&term!(a), &term!(f(b))));
+    assert_eq!(
+        premise.slice(&[&term!(d)]),
+        premise! {
+            f(b) = c;
+            b = d;
+        }
+    );
+
+    assert!(premise.remove(&term!(f(b)), &term!(c)));
+    assert_eq!(premise.slice(&[&term!(d)]), premise! { b = d; });
+    assert_eq!(premise.slice(&[&term!(f(a))]), Premise::default());
+    assert!(!equals(&term!(a), &term!(c), &premise));
+}
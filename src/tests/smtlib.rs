@@ -0,0 +1,184 @@
+use crate::{equals, parse_smtlib, premise, term, to_smtlib, SmtLibError, SmtLibErrorKind, Term};
+
+#[test]
+fn import_qf_uf() {
+    let problem = parse_smtlib(
+        "
+        (set-logic QF_UF)
+        (set-info :status unsat)
+        (declare-sort U 0)
+        (declare-fun a () U)
+        (declare-const b U)
+        (declare-fun |c d| () U)
+        (declare-fun f (U U) U)
+        (define-fun g ((x U)) U (f x b))
+        (assert (= a b |c d|))
+        (assert (and (not (distinct (f a a) b)) true))
+        (assert (not (= (g a) (f b (! a :named n)))))
+        (declare-const e U)
+        (assert (distinct a e b))
+        (check-sat) ; the first query holds
+        (exit)
+        ",
+    )
+    .unwrap();
+
+    assert_eq!(
+        problem.premise,
+        premise! {
+            a = b;
+            b = {Term::Literal("c d".to_string())};
+            f(a, a) = b;
+            norm g(x) := f(x, b);
+        }
+    );
+    assert_eq!(
+        problem.queries,
+        [
+            (term!(@g(a)), term!(f(b, a))),
+            (term!(a), term!(e)),
+            (term!(a), term!(b)),
+            (term!(e), term!(b)),
+        ]
+    );
+
+    assert!(equals(
+        &problem.queries[0].0,
+        &problem.queries[0].1,
+        &problem.premise
+    ));
+    assert!(!equals(
+        &problem.queries[1].0,
+        &problem.queries[1].1,
+        &problem.premise
+    ));
+}
+
+#[test]
+fn unused_parameters() {
+    let problem = parse_smtlib(
+        "
+        (declare-sort U 0)
+        (declare-const a U)
+        (declare-const b U)
+        (define-fun f ((x U) (y U)) U x)
+        (define-fun c ((x U)) U a)
+        (assert (not (= (f a b) (c b))))
+        ",
+    )
+    .unwrap();
+
+    assert_eq!(
+        problem.premise,
+        premise! {
+            norm f(x) := x;
+            norm c() := a;
+        }
+    );
+    assert_eq!(problem.queries, [(term!(@f(a)), term!(@c()))]);
+    assert!(equals(
+        &problem.queries[0].0,
+        &problem.queries[0].1,
+        &problem.premise
+    ));
+}
+
+#[test]
+fn errors() {
+    let error = |source| parse_smtlib(source).unwrap_err();
+
+    assert_eq!(
+        error("(declare-fun f (U) U)\n(assert (= (f) f))"),
+        SmtLibError {
+            line: 2,
+            column: 12,
+            kind: SmtLibErrorKind::ArityMismatch {
+                symbol: "f".to_string(),
+                expected: 1,
+                found: 0,
+            },
+        }
+    );
+    assert_eq!(
+        error("(assert (= a b))").kind,
+        SmtLibErrorKind::UndeclaredSymbol("a".to_string())
+    );
+    assert_eq!(
+        error("(declare-fun p (U) Bool)").kind,
+        SmtLibErrorKind::Unsupported("Bool".to_string())
+    );
+    assert_eq!(
+        error("(push 1)").kind,
+        SmtLibErrorKind::Unsupported("push".to_string())
+    );
+    assert_eq!(
+        error("(declare-const a U)\n(assert (or (= a a)))").kind,
+        SmtLibErrorKind::Unsupported("(or (= a a))".to_string())
+    );
+    assert_eq!(
+        error("(declare-const a U)\n(assert (= a))"),
+        SmtLibError {
+            line: 2,
+            column: 9,
+            kind: SmtLibErrorKind::Malformed("at least two operands"),
+        }
+    );
+    assert_eq!(
+        error("(declare-const a U)\n(assert (not (distinct a)))").kind,
+        SmtLibErrorKind::Malformed("at least two operands")
+    );
+    assert_eq!(
+        error("(declare-const a U)\n(declare-const a U)").kind,
+        SmtLibErrorKind::DuplicateDeclaration("a".to_string())
+    );
+    assert_eq!(
+        error("(assert (= a a)").kind,
+        SmtLibErrorKind::UnexpectedEndOfInput
+    );
+}
+
+#[test]
+fn export_round_trip() {
+    let premise = premise! {
+        a = f(b);
+        f(a) = @N(b, c);
+        norm N(x, y) := g(x, @M(y));
+        norm M(x) := f(x);
+    };
+    let (lhs, rhs) = (term!(@N(a, a)), term!(g(f(b), f(a))));
+
+    let script = to_smtlib(&premise, &lhs, &rhs);
+    assert!(script.starts_with("(set-logic QF_UF)\n(declare-sort U 0)\n"));
+    assert!(script.ends_with("(assert (not (= (N a a) (g (f b) (f a)))))\n(check-sat)\n(exit)\n"));
+
+    let problem = parse_smtlib(&script).unwrap();
+    assert_eq!(problem.premise, premise);
+    assert_eq!(problem.queries, [(lhs, rhs)]);
+}
+
+#[test]
+fn export_clashing_symbols() {
+    let premise = premise! {
+        f = f(and);
+        norm g(f) := f(f);
+        norm r() := @s;
+        norm s() := @r;
+    };
+
+    assert_eq!(
+        to_smtlib(&premise, &term!(@g(f)), &term!(f(@g(and, and)))),
+        "\
+(set-logic UF)
+(declare-sort U 0)
+(declare-fun and@1 () U)
+(declare-fun f () U)
+(declare-fun f@1 (U) U)
+(declare-fun g (U U) U)
+(define-funs-rec ((g@1 ((f@2 U)) U) (r () U) (s () U)) ((f@1 f@2) s r))
+(assert (= f (f@1 and@1)))
+(assert (not (= (g@1 f) (f@1 (g and@1 and@1)))))
+(check-sat)
+(exit)
+"
+    );
+}
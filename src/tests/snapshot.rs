@@ -0,0 +1,149 @@
+use crate::{premise, term, Function, Premise, SnapshotError, SymbolAxioms, Term};
+
+fn snapshot(premise: &Premise<String>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    premise.write_to(&mut bytes).unwrap();
+    bytes
+}
+
+#[test]
+fn round_trip() {
+    let mut premise = premise! {
+        a = f(b, @N(c));
+        f(a, a) = g(f(b, @N(c)));
+        norm N(x) := h(x, f(x, x));
+        norm M() := a;
+    };
+    premise.insert_axioms("g".to_string(), SymbolAxioms::ac());
+    premise.insert_axioms(
+        "h".to_string(),
+        SymbolAxioms {
+            idempotent: true,
+            identity: Some(term!(f(b, @N(c)))),
+            ..SymbolAxioms::commutative()
+        },
+    );
+
+    let bytes = snapshot(&premise);
+    assert!(bytes.starts_with(b"FOLQ\x02\x00\x00\x00"));
+    assert_eq!(
+        Premise::<String>::read_from(bytes.as_slice()).unwrap(),
+        premise
+    );
+
+    let empty = Premise::<String>::default();
+    assert_eq!(
+        Premise::<String>::read_from(snapshot(&empty).as_slice()).unwrap(),
+        empty
+    );
+}
+
+#[test]
+fn shared_subterms() {
+    let mut term = term!(a);
+    for _ in 0..12 {
+        term = term!(f({ term.clone() }, { term }));
+    }
+
+    let premise = premise! {
+        b = {term.clone()};
+        c = g({term});
+    };
+    let bytes = snapshot(&premise);
+
+    assert!(bytes.len() < 1024);
+    assert_eq!(
+        Premise::<String>::read_from(bytes.as_slice()).unwrap(),
+        premise
+    );
+}
+
+#[test]
+fn deterministic() {
+    let build = |reversed: bool| {
+        let mut equalities = (0..50)
+            .map(|index| {
+                (
+                    Term::Function(Function {
+                        symbol: format!("f{index}"),
+                        arguments: vec![term!(a)],
+                    }),
+                    Term::Literal(format!("x{index}")),
+                )
+            })
+            .collect::<Vec<_>>();
+        if reversed {
+            equalities.reverse();
+        }
+
+        let mut premise = Premise::new_with_equalities(equalities);
+        for symbol in ["N", "M", "K"] {
+            premise
+                .insert_normalization(symbol.to_string(), vec!["y".to_string()], term!(g(y)))
+                .unwrap();
+            premise.insert_axioms(symbol.to_lowercase(), SymbolAxioms::ac());
+        }
+
+        premise
+    };
+
+    assert_eq!(snapshot(&build(false)), snapshot(&build(true)));
+}
+
+#[test]
+fn integer_literals() {
+    let premise = premise! { [|symbol: &str| symbol.len() as u64]
+        aa = bbb(c);
+    };
+
+    let mut bytes = Vec::new();
+    premise.write_to(&mut bytes).unwrap();
+
+    assert_eq!(
+        Premise::<u64>::read_from(bytes.as_slice()).unwrap(),
+        premise
+    );
+    assert!(matches!(
+        Premise::<u32>::read_from(bytes.as_slice()),
+        Err(SnapshotError::Corrupted(_))
+    ));
+}
+
+#[test]
+fn rejects_invalid_data() {
+    let read = |bytes: &[u8]| Premise::<String>::read_from(bytes).unwrap_err();
+    let bytes = snapshot(&premise! { a = f(b); });
+
+    assert!(matches!(read(b"JSON{}"), SnapshotError::InvalidMagic));
+
+    let mut version = bytes.clone();
+    version[4] = 3;
+    assert!(matches!(
+        read(&version),
+        SnapshotError::UnsupportedVersion(3)
+    ));
+
+    let mut flipped = bytes.clone();
+    flipped[10] ^= 1;
+    assert!(matches!(read(&flipped), SnapshotError::ChecksumMismatch));
+
+    assert!(matches!(
+        read(&bytes[..bytes.len() - 1]),
+        SnapshotError::ChecksumMismatch
+    ));
+    assert!(matches!(read(&bytes[..10]), SnapshotError::Corrupted(_)));
+}
+
+#[test]
+fn reads_version_1() {
+    let mut bytes = b"FOLQ\x01\x00\x00\x00\x01\x01a\x01\x00\x00\x01\x00\x00\x00".to_vec();
+    let checksum = bytes.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    bytes.extend_from_slice(&checksum.to_le_bytes());
+
+    assert_eq!(
+        Premise::<String>::read_from(bytes.as_slice()).unwrap(),
+        premise! { a = a; }
+    );
+}
@@ -0,0 +1,128 @@
+use crate::{
+    parse_premise, parse_term, Function, Normalizable, ParseError, ParseErrorKind, Premise, Term,
+};
+
+fn literal(name: &str) -> Term<String> {
+    Term::Literal(name.to_string())
+}
+
+#[test]
+fn parse_terms() {
+    assert_eq!(parse_term("x"), Ok(literal("x")));
+    assert_eq!(
+        parse_term("f(x, g(y), h())"),
+        Ok(Term::Function(Function {
+            symbol: "f".to_string(),
+            arguments: vec![
+                literal("x"),
+                Term::Function(Function {
+                    symbol: "g".to_string(),
+                    arguments: vec![literal("y")],
+                }),
+                Term::Function(Function {
+                    symbol: "h".to_string(),
+                    arguments: Vec::new(),
+                }),
+            ],
+        }))
+    );
+    assert_eq!(
+        parse_term("@norm(a)"),
+        Ok(Term::Normalizable(Normalizable {
+            symbol: "norm".to_string(),
+            arguments: vec![literal("a")],
+        }))
+    );
+    assert_eq!(parse_term("@n"), parse_term("@n()"));
+}
+
+#[test]
+fn parse_premises() {
+    let premise = parse_premise(
+        "
+        // the equalities
+        x = f(y);
+        norm = y;
+        norm N(a, b) := f(a, @M(b));
+        norm M() := z;
+        ",
+    )
+    .unwrap();
+
+    let mut expected = Premise::default();
+    expected.insert(literal("x"), parse_term("f(y)").unwrap());
+    expected.insert(literal("norm"), literal("y"));
+    expected
+        .insert_normalization(
+            "N".to_string(),
+            vec!["a".to_string(), "b".to_string()],
+            parse_term("f(a, @M(b))").unwrap(),
+        )
+        .unwrap();
+    expected
+        .insert_normalization("M".to_string(), Vec::new(), literal("z"))
+        .unwrap();
+
+    assert_eq!(premise, expected);
+}
+
+#[test]
+fn round_trip() {
+    let source = "a = b;\nf(@N(a), x) = g();\nnorm N(p, q) := h(p, @M(q));\n";
+    let premise = parse_premise(source).unwrap();
+    let printed = premise.to_string();
+
+    assert_eq!(parse_premise(&printed), Ok(premise));
+
+    let term = parse_term("f(@N(a, x), g(), y)").unwrap();
+    assert_eq!(term.to_string().parse::<Term<String>>(), Ok(term));
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        parse_term("f(x,\n  ?)"),
+        Err(ParseError {
+            line: 2,
+            column: 3,
+            kind: ParseErrorKind::UnexpectedCharacter('?'),
+        })
+    );
+    assert_eq!(
+        parse_term("f(x y)"),
+        Err(ParseError {
+            line: 1,
+            column: 5,
+            kind: ParseErrorKind::UnexpectedToken {
+                expected: "`,` or `)`",
+                found: "`y`".to_string(),
+            },
+        })
+    );
+    assert_eq!(
+        parse_premise("x = y"),
+        Err(ParseError {
+            line: 1,
+            column: 6,
+            kind: ParseErrorKind::UnexpectedToken {
+                expected: "`;`",
+                found: "end of input".to_string(),
+            },
+        })
+    );
+    assert_eq!(
+        parse_premise("norm N() := x;\nnorm N(a) := a;"),
+        Err(ParseError {
+            line: 2,
+            column: 6,
+            kind: ParseErrorKind::DuplicateNormalization("N".to_string()),
+        })
+    );
+
+    let error = parse_premise("a = b;\nnorm N(x, y) := f(x);").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 6));
+    assert_eq!(
+        error.to_string(),
+        "2:6: parameter `y` of normalization `N` does not appear in its equivalence"
+    );
+}
@@ -0,0 +1,183 @@
+use crate::{
+    equals, equals_with_theories, premise, term, Arithmetic, Conflict, Equality, Function, Oracle,
+    Premise, Term, Theory,
+};
+
+/// Evaluates `succ(n)` for integers `n`.
+#[derive(Debug)]
+struct Successor;
+
+impl Successor {
+    fn evaluate(term: &Term<String>) -> Option<i64> {
+        match term {
+            Term::Literal(integer) => integer.parse().ok(),
+            Term::Function(Function { symbol, arguments }) if symbol == "succ" => {
+                let [argument] = arguments.as_slice() else {
+                    return None;
+                };
+                Some(Self::evaluate(argument)? + 1)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Theory<String> for Successor {
+    fn normalize(&self, term: &Term<String>, _: &Premise<String>) -> Option<Term<String>> {
+        if matches!(term, Term::Literal(_)) {
+            return None;
+        }
+
+        Self::evaluate(term).map(|integer| Term::Literal(integer.to_string()))
+    }
+}
+
+/// Makes `Pair` injective and distinct integers different.
+#[derive(Debug)]
+struct Constructors;
+
+impl Theory<String> for Constructors {
+    fn propagate(
+        &self,
+        lhs: &Term<String>,
+        rhs: &Term<String>,
+        _: &mut Oracle<'_, String>,
+    ) -> Result<Vec<Equality<String>>, Conflict> {
+        match (lhs, rhs) {
+            (Term::Function(lhs), Term::Function(rhs))
+                if lhs.symbol == "Pair" && rhs.symbol == "Pair" =>
+            {
+                Ok(lhs
+                    .arguments
+                    .iter()
+                    .cloned()
+                    .zip(rhs.arguments.iter().cloned())
+                    .collect())
+            }
+            (Term::Literal(lhs), Term::Literal(rhs))
+                if lhs.parse::<i64>().is_ok() && rhs.parse::<i64>().is_ok() =>
+            {
+                Err(Conflict)
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+}
+
+/// Makes `max` commutative by asking the oracle about the swapped arguments.
+#[derive(Debug)]
+struct Maximum;
+
+impl Theory<String> for Maximum {
+    fn equals(
+        &self,
+        term1: &Term<String>,
+        term2: &Term<String>,
+        oracle: &mut Oracle<'_, String>,
+    ) -> bool {
+        match (term1, term2) {
+            (Term::Function(lhs), Term::Function(rhs))
+                if lhs.symbol == "max" && rhs.symbol == "max" && lhs.arguments.len() == 2 =>
+            {
+                rhs.arguments.len() == 2
+                    && oracle.equals(&lhs.arguments[0], &rhs.arguments[1])
+                    && oracle.equals(&lhs.arguments[1], &rhs.arguments[0])
+            }
+            _ => false,
+        }
+    }
+}
+
+#[test]
+fn normalize() {
+    let premise = premise! { norm N(x) := succ(x); };
+
+    assert!(equals_with_theories(
+        &term!(f(succ(succ(1)))),
+        &term!(f(3)),
+        &premise,
+        &[&Successor]
+    ));
+    assert!(equals_with_theories(
+        &term!(@N(4)),
+        &term!(5),
+        &premise,
+        &[&Successor]
+    ));
+    assert!(!equals(&term!(@N(4)), &term!(5), &premise));
+    assert!(equals_with_theories(
+        &term!(@N(a)),
+        &term!(succ(a)),
+        &premise,
+        &[]
+    ));
+}
+
+#[test]
+fn propagate() {
+    let premise = premise! { Pair(a, Pair(b, c)) = Pair(d, e); };
+
+    assert!(equals_with_theories(
+        &term!(f(a)),
+        &term!(f(d)),
+        &premise,
+        &[&Constructors]
+    ));
+    assert!(equals_with_theories(
+        &term!(Pair(b, c)),
+        &term!(e),
+        &premise,
+        &[&Constructors]
+    ));
+    assert!(!equals_with_theories(
+        &term!(b),
+        &term!(c),
+        &premise,
+        &[&Constructors]
+    ));
+
+    // `1 = 2` is propagated, which is a conflict
+    let premise = premise! { Pair(1, d) = Pair(2, d); };
+    assert!(equals_with_theories(
+        &term!(b),
+        &term!(x),
+        &premise,
+        &[&Constructors]
+    ));
+}
+
+#[test]
+fn oracle() {
+    let premise = premise! { a = b; };
+
+    assert!(equals_with_theories(
+        &term!(g(max(a, c))),
+        &term!(g(max(c, b))),
+        &premise,
+        &[&Maximum]
+    ));
+    assert!(!equals_with_theories(
+        &term!(max(a, c)),
+        &term!(max(d, b)),
+        &premise,
+        &[&Maximum]
+    ));
+}
+
+#[test]
+fn combined_theories() {
+    let arithmetic = Arithmetic::new("add".to_string(), "mul".to_string());
+
+    assert!(equals_with_theories(
+        &term!(Array(T, add(1, succ(N)))),
+        &term!(Array(T, add(succ(N), 1))),
+        &Premise::default(),
+        &[&Successor, &arithmetic]
+    ));
+    assert!(equals_with_theories(
+        &term!(Array(T, succ(succ(0)))),
+        &term!(Array(T, add(1, 1))),
+        &Premise::default(),
+        &[&Successor, &arithmetic]
+    ));
+}
@@ -0,0 +1,91 @@
+use crate::{equals, parse_tptp, premise, term, Term, TptpError, TptpErrorKind};
+
+#[test]
+fn import_fof() {
+    let problem = parse_tptp(
+        "
+        % a small group-like problem
+        fof(ax1, axiom, a = b & f(a) = c).
+        fof(def, definition, ![X, Y]: g(X, Y) = f(h(Y, X))).
+        fof(ax2, axiom, ( 'quoted' = g(a, b) ), file('x.p', ax2)).
+        /* the goal */
+        fof(goal, conjecture, ![Z]: g(Z, b) = f(h(b, Z))).
+        ",
+    )
+    .unwrap();
+
+    assert_eq!(
+        problem.premise,
+        premise! {
+            a = b;
+            f(a) = c;
+            {Term::Literal("'quoted'".to_string())} = @g(a, b);
+            norm g(X, Y) := f(h(Y, X));
+        }
+    );
+    assert_eq!(
+        problem.conjecture,
+        Some((term!(@g(Z, b)), term!(f(h(b, Z)))))
+    );
+
+    let (lhs, rhs) = problem.conjecture.unwrap();
+    assert!(equals(&lhs, &rhs, &problem.premise));
+}
+
+#[test]
+fn import_cnf() {
+    let problem = parse_tptp(
+        "
+        cnf(c1, axiom, a = f(b)).
+        cnf(c2, axiom, (f(b) = c)).
+        cnf(c3, negated_conjecture, ~ (a = c)).
+        ",
+    )
+    .unwrap();
+
+    let (lhs, rhs) = problem.conjecture.unwrap();
+    assert_eq!((&lhs, &rhs), (&term!(a), &term!(c)));
+    assert!(equals(&lhs, &rhs, &problem.premise));
+}
+
+#[test]
+fn errors() {
+    let error = |source| parse_tptp(source).unwrap_err();
+
+    assert_eq!(
+        error("fof(a, axiom, a = b).\nfof(b, axiom, a = b | b = c)."),
+        TptpError {
+            line: 2,
+            column: 21,
+            kind: TptpErrorKind::Unsupported("the connective `|`".to_string()),
+        }
+    );
+    assert_eq!(
+        error("cnf(a, axiom, p(a)).").kind,
+        TptpErrorKind::Unsupported("a predicate".to_string())
+    );
+    assert_eq!(
+        error("fof(a, axiom, ![X]: f(X) = f(f(X))).").kind,
+        TptpErrorKind::Unsupported("a non-ground equation that is not a definition".to_string())
+    );
+    assert_eq!(
+        error("fof(a, axiom, ![X, Y]: f(X, Y) = X).").kind,
+        TptpErrorKind::Unsupported("a non-ground equation that is not a definition".to_string())
+    );
+    assert_eq!(
+        error("fof(a, conjecture, a = b).\nfof(b, conjecture, b = c).").kind,
+        TptpErrorKind::MultipleConjectures
+    );
+    assert_eq!(
+        error("fof(a, axiom, a != b).").kind,
+        TptpErrorKind::Unsupported("a negated equation in an axiom".to_string())
+    );
+    assert_eq!(
+        error("include('Axioms/GRP001.ax').").kind,
+        TptpErrorKind::Unsupported("`include`".to_string())
+    );
+    assert_eq!(
+        error("fof(a, axiom, ![X]: f(X) = X).\nfof(b, axiom, ![Y]: f(Y) = g(Y)).").kind,
+        TptpErrorKind::DuplicateDefinition("f".to_string())
+    );
+}
@@ -0,0 +1,123 @@
+use super::{id, ID};
+use crate::{term, Context, Folder, Function, Normalizable, Term, Visitor, VisitorMut};
+
+fn sample() -> Term<ID> {
+    term!([id] f0(x1, @n2(x3)))
+}
+
+#[derive(Default)]
+struct OrderRecorder {
+    pre: Vec<(Term<ID>, Option<usize>, usize)>,
+    post: Vec<Term<ID>>,
+}
+
+impl Visitor<ID> for OrderRecorder {
+    fn visit_pre(&mut self, term: &Term<ID>, context: Context<'_, ID>) -> bool {
+        self.pre.push((term.clone(), context.index, context.depth));
+        true
+    }
+
+    fn visit_post(&mut self, term: &Term<ID>, context: Context<'_, ID>) -> bool {
+        if let Some(parent) = context.parent {
+            let (Term::Function(Function { arguments, .. })
+            | Term::Normalizable(Normalizable { arguments, .. })) = parent
+            else {
+                panic!("a literal cannot be a parent");
+            };
+
+            assert_eq!(&arguments[context.index.unwrap()], term);
+        }
+
+        self.post.push(term.clone());
+        true
+    }
+}
+
+#[test]
+fn pre_and_post_order() {
+    let term = sample();
+    let mut recorder = OrderRecorder::default();
+
+    assert!(term.visit(&mut recorder));
+
+    let Term::Function(Function { arguments, .. }) = &term else {
+        unreachable!()
+    };
+    let Term::Normalizable(Normalizable {
+        arguments: inner, ..
+    }) = &arguments[1]
+    else {
+        unreachable!()
+    };
+
+    assert_eq!(
+        recorder.pre,
+        vec![
+            (term.clone(), None, 0),
+            (arguments[0].clone(), Some(0), 1),
+            (arguments[1].clone(), Some(1), 1),
+            (inner[0].clone(), Some(0), 2),
+        ]
+    );
+    assert_eq!(
+        recorder.post,
+        vec![
+            arguments[0].clone(),
+            inner[0].clone(),
+            arguments[1].clone(),
+            term.clone(),
+        ]
+    );
+}
+
+struct StopAtNormalizable {
+    visited: usize,
+}
+
+impl Visitor<ID> for StopAtNormalizable {
+    fn visit(&mut self, term: &Term<ID>) -> bool {
+        self.visited += 1;
+        !matches!(term, Term::Normalizable(..))
+    }
+}
+
+#[test]
+fn stop_visiting() {
+    let mut visitor = StopAtNormalizable { visited: 0 };
+
+    assert!(!sample().visit(&mut visitor));
+    assert_eq!(visitor.visited, 3);
+}
+
+struct Rename;
+
+impl VisitorMut<ID> for Rename {
+    fn visit_mut(&mut self, term: &mut Term<ID>) -> bool {
+        if let Term::Literal(ID(id)) = term {
+            *id += 10;
+        }
+        true
+    }
+}
+
+#[test]
+fn rewrite_in_place() {
+    let mut term = sample();
+
+    assert!(term.visit_mut(&mut Rename));
+    assert_eq!(term, term!([id] f0(x11, @n2(x13))));
+}
+
+/// Replaces every normalizable term by its first argument.
+struct DropNormalizable;
+
+impl Folder<ID> for DropNormalizable {
+    fn fold_normalizable(&mut self, normalizable: Normalizable<ID>) -> Term<ID> {
+        normalizable.arguments.into_iter().next().unwrap()
+    }
+}
+
+#[test]
+fn fold_bottom_up() {
+    assert_eq!(sample().fold(&mut DropNormalizable), term!([id] f0(x1, x3)));
+}
@@ -0,0 +1,150 @@
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::{dfs, Equality, Premise, Term};
+
+/// Reported by [`Theory::propagate`] or [`Theory::check`] when the premise contradicts the
+/// theory.
+///
+/// A premise that contradicts a theory entails every equality.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Conflict;
+
+/// An interpreted theory that the equality engine consults in addition to the premise, see
+/// [`equals_with_theories`](crate::equals_with_theories).
+///
+/// All methods have default implementations that do nothing, so a theory only implements the
+/// hooks it needs.
+pub trait Theory<Literal: Ord + Eq + Hash + Clone + Debug> {
+    /// Rewrites a term into an equal term, for example by evaluating it.
+    ///
+    /// Returns `None` if the theory does not rewrite the term. A term must not be rewritten into
+    /// itself, and rewriting must eventually stop.
+    ///
+    /// The engine consults this for the terms it compares, not for their subterms, so a theory
+    /// that evaluates nested terms does so itself.
+    fn normalize(&self, term: &Term<Literal>, premise: &Premise<Literal>) -> Option<Term<Literal>> {
+        let _ = (term, premise);
+        None
+    }
+
+    /// Returns `true` if the theory shows the two terms to be equal.
+    ///
+    /// The oracle answers equality questions about other terms using the premise and all
+    /// theories.
+    fn equals(
+        &self,
+        term1: &Term<Literal>,
+        term2: &Term<Literal>,
+        oracle: &mut Oracle<'_, Literal>,
+    ) -> bool {
+        let _ = (term1, term2, oracle);
+        false
+    }
+
+    /// Returns the equalities that follow from `lhs = rhs` in the theory, or a [`Conflict`] if
+    /// the equality contradicts the theory.
+    ///
+    /// This is called for every equality of the premise and for every equality propagated by
+    /// any theory, until no theory propagates a new equality. Propagation must eventually stop,
+    /// so a theory must not keep propagating equalities between new terms, such as ever larger
+    /// sums, or [`equals_with_theories`](crate::equals_with_theories) does not return.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Conflict`] if the equality contradicts the theory.
+    fn propagate(
+        &self,
+        lhs: &Term<Literal>,
+        rhs: &Term<Literal>,
+        oracle: &mut Oracle<'_, Literal>,
+    ) -> Result<Vec<Equality<Literal>>, Conflict> {
+        let _ = (lhs, rhs, oracle);
+        Ok(Vec::new())
+    }
+
+    /// Returns a [`Conflict`] if the premise as a whole contradicts the theory.
+    ///
+    /// This is called once, after every equality has been [propagated](Self::propagate), so it
+    /// suits checks that do not depend on a particular equality.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Conflict`] if the premise contradicts the theory.
+    fn check(&self, oracle: &mut Oracle<'_, Literal>) -> Result<(), Conflict> {
+        let _ = oracle;
+        Ok(())
+    }
+}
+
+/// The built-in theory that unfolds [`Normalizable`](crate::Normalizable) terms into the
+/// equivalence of their [`Normalization`](crate::Normalization) in the premise.
+///
+/// [`equals`](crate::equals) and [`equals_with_theories`](crate::equals_with_theories) always
+/// consult this theory first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Unfolding;
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Theory<Literal> for Unfolding {
+    fn normalize(&self, term: &Term<Literal>, premise: &Premise<Literal>) -> Option<Term<Literal>> {
+        let Term::Normalizable(normalizable) = term else {
+            return None;
+        };
+
+        premise
+            .get_normalization(&normalizable.symbol)?
+            .equivalence(&normalizable.arguments)
+            .ok()
+    }
+}
+
+/// Answers equality questions on behalf of a [`Theory`].
+pub struct Oracle<'a, Literal: Ord + Eq + Hash + Clone + Debug> {
+    pub(crate) premise: &'a Premise<Literal>,
+    pub(crate) theories: &'a [&'a dyn Theory<Literal>],
+    pub(crate) visited: &'a mut HashSet<(Term<Literal>, Term<Literal>)>,
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Debug for Oracle<'_, Literal> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Oracle")
+            .field("premise", &self.premise)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Oracle<'_, Literal> {
+    /// Returns the premise that the question is asked under.
+    #[must_use]
+    pub const fn premise(&self) -> &Premise<Literal> {
+        self.premise
+    }
+
+    /// Determines if two terms are equal under the premise and all theories.
+    ///
+    /// Questions that are already being answered further up are answered with `false`, so that
+    /// a theory asking about the terms it was asked about does not loop forever.
+    pub fn equals(&mut self, term1: &Term<Literal>, term2: &Term<Literal>) -> bool {
+        dfs(term1, term2, self.premise, self.theories, self.visited)
+    }
+
+    /// Determines if two terms are equal under the premise extended with the given equalities
+    /// and all theories.
+    ///
+    /// This is useful for case splits on whether two terms are equal.
+    #[must_use]
+    pub fn equals_assuming(
+        &self,
+        term1: &Term<Literal>,
+        term2: &Term<Literal>,
+        assumptions: &[Equality<Literal>],
+    ) -> bool {
+        let mut premise = self.premise.clone();
+        for (lhs, rhs) in assumptions {
+            premise.insert(lhs.clone(), rhs.clone());
+        }
+
+        dfs(term1, term2, &premise, self.theories, &mut HashSet::new())
+    }
+}
@@ -0,0 +1,710 @@
+//! Import of the ground and unit-equational subset of TPTP problems.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+use crate::{Function, Normalizable, NormalizationError, Premise, Term};
+
+/// A problem read from a TPTP file, see [`parse_tptp`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TptpProblem {
+    /// The equalities and normalizations given by the axioms of the problem.
+    pub premise: Premise<String>,
+
+    /// The equation to be proven, if the problem has a conjecture.
+    pub conjecture: Option<(Term<String>, Term<String>)>,
+}
+
+/// The reason why importing a TPTP problem failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TptpErrorKind {
+    /// A character that cannot start any token was found.
+    UnexpectedCharacter(char),
+
+    /// A token other than the expected one was found.
+    UnexpectedToken {
+        /// The description of what was expected.
+        expected: &'static str,
+
+        /// The description of the token that was found.
+        found: String,
+    },
+
+    /// A construct outside of the supported subset was found.
+    Unsupported(String),
+
+    /// The problem has more than one conjecture.
+    MultipleConjectures,
+
+    /// The same function symbol is defined by more than one universally quantified equation.
+    DuplicateDefinition(String),
+
+    /// A definition was rejected by [`Premise::insert_normalization`].
+    InvalidDefinition(NormalizationError<String>),
+}
+
+impl Display for TptpErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedCharacter(character) => {
+                write!(f, "unexpected character `{character}`")
+            }
+            Self::UnexpectedToken { expected, found } => {
+                write!(f, "expected {expected}, found {found}")
+            }
+            Self::Unsupported(construct) => write!(f, "{construct} is not supported"),
+            Self::MultipleConjectures => write!(f, "the problem has more than one conjecture"),
+            Self::DuplicateDefinition(symbol) => {
+                write!(f, "`{symbol}` is defined more than once")
+            }
+            Self::InvalidDefinition(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+/// An error produced when importing a TPTP problem, see [`parse_tptp`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TptpError {
+    /// The line where the error occurred, starting from 1.
+    pub line: usize,
+
+    /// The column where the error occurred, starting from 1.
+    pub column: usize,
+
+    /// The reason of the error.
+    pub kind: TptpErrorKind,
+}
+
+impl Display for TptpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.kind)
+    }
+}
+
+impl std::error::Error for TptpError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    LowerWord(String),
+    UpperWord(String),
+    Other(String),
+    Punctuation(&'static str),
+    EndOfInput,
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LowerWord(word) | Self::UpperWord(word) | Self::Other(word) => {
+                write!(f, "`{word}`")
+            }
+            Self::Punctuation(punctuation) => write!(f, "`{punctuation}`"),
+            Self::EndOfInput => write!(f, "end of input"),
+        }
+    }
+}
+
+/// The punctuation of the TPTP syntax, longest first so that prefixes match last.
+const PUNCTUATIONS: &[&str] = &[
+    "<~>", "<=>", "=>", "<=", "~|", "~&", "!=", "(", ")", "[", "]", ",", ".", ":", "!", "?", "~",
+    "&", "|", "=",
+];
+
+fn tokenize(source: &str) -> Result<Vec<(Token, usize, usize)>, TptpError> {
+    let mut tokens = Vec::new();
+    let (mut line, mut column) = (1, 1);
+    let mut rest = source;
+
+    let advance = |text: &str, line: &mut usize, column: &mut usize| {
+        for character in text.chars() {
+            if character == '\n' {
+                *line += 1;
+                *column = 1;
+            } else {
+                *column += 1;
+            }
+        }
+    };
+
+    while let Some(character) = rest.chars().next() {
+        let (start_line, start_column) = (line, column);
+
+        let (token, length) = if character.is_whitespace() {
+            (None, character.len_utf8())
+        } else if character == '%' {
+            (None, rest.find('\n').unwrap_or(rest.len()))
+        } else if rest.starts_with("/*") {
+            let end = rest.find("*/").map_or(rest.len(), |end| end + 2);
+            (None, end)
+        } else if let Some(punctuation) = PUNCTUATIONS.iter().find(|p| rest.starts_with(**p)) {
+            (Some(Token::Punctuation(punctuation)), punctuation.len())
+        } else if character == '\'' || character == '"' {
+            let length = rest[1..]
+                .find(character)
+                .map(|end| end + 2)
+                .ok_or_else(|| TptpError {
+                    line,
+                    column,
+                    kind: TptpErrorKind::UnexpectedToken {
+                        expected: "a closing quote",
+                        found: Token::EndOfInput.to_string(),
+                    },
+                })?;
+            let word = rest[..length].to_string();
+
+            if character == '\'' {
+                (Some(Token::LowerWord(word)), length)
+            } else {
+                (Some(Token::Other(word)), length)
+            }
+        } else if character.is_ascii_alphanumeric() || character == '$' || character == '_' {
+            let length = rest[1..]
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .map_or(rest.len(), |end| end + 1);
+            let word = rest[..length].to_string();
+
+            let token = if character.is_ascii_lowercase() {
+                Token::LowerWord(word)
+            } else if character.is_ascii_uppercase() {
+                Token::UpperWord(word)
+            } else {
+                Token::Other(word)
+            };
+            (Some(token), length)
+        } else {
+            return Err(TptpError {
+                line,
+                column,
+                kind: TptpErrorKind::UnexpectedCharacter(character),
+            });
+        };
+
+        advance(&rest[..length], &mut line, &mut column);
+        rest = &rest[length..];
+
+        if let Some(token) = token {
+            tokens.push((token, start_line, start_column));
+        }
+    }
+
+    tokens.push((Token::EndOfInput, line, column));
+
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RawTerm {
+    Variable(String),
+    Application(String, Vec<Self>),
+}
+
+impl RawTerm {
+    fn variables<'a>(&'a self, variables: &mut HashSet<&'a str>) {
+        match self {
+            Self::Variable(variable) => {
+                variables.insert(variable);
+            }
+            Self::Application(_, arguments) => {
+                for argument in arguments {
+                    argument.variables(variables);
+                }
+            }
+        }
+    }
+
+    fn mentions(&self, symbol: &str) -> bool {
+        match self {
+            Self::Variable(_) => false,
+            Self::Application(functor, arguments) => {
+                functor == symbol || arguments.iter().any(|argument| argument.mentions(symbol))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Equation {
+    lhs: RawTerm,
+    rhs: RawTerm,
+    negated: bool,
+    line: usize,
+    column: usize,
+}
+
+impl Equation {
+    const fn error(&self, kind: TptpErrorKind) -> TptpError {
+        TptpError {
+            line: self.line,
+            column: self.column,
+            kind,
+        }
+    }
+
+    fn is_ground(&self) -> bool {
+        let mut variables = HashSet::new();
+        self.lhs.variables(&mut variables);
+        self.rhs.variables(&mut variables);
+        variables.is_empty()
+    }
+
+    /// Returns the defined functor, the parameters and the body if the equation has the form
+    /// `f(X1, ..., Xn) = body` with distinct variables `Xi` that are exactly the variables of
+    /// `body`, in which `f` does not occur.
+    ///
+    /// A variable that does not occur in `body` cannot be a parameter of a normalization, see
+    /// [`NormalizationError::UnboundLiteral`], so such an equation is not a definition.
+    fn as_definition(&self) -> Option<(&str, Vec<String>, &RawTerm)> {
+        [(&self.lhs, &self.rhs), (&self.rhs, &self.lhs)]
+            .into_iter()
+            .find_map(|(head, body)| {
+                let RawTerm::Application(functor, arguments) = head else {
+                    return None;
+                };
+
+                let mut parameters = Vec::new();
+                for argument in arguments {
+                    match argument {
+                        RawTerm::Variable(variable) if !parameters.contains(variable) => {
+                            parameters.push(variable.clone());
+                        }
+                        _ => return None,
+                    }
+                }
+
+                let mut variables = HashSet::new();
+                body.variables(&mut variables);
+
+                (variables.len() == parameters.len()
+                    && variables.iter().all(|v| parameters.iter().any(|p| p == v))
+                    && !body.mentions(functor))
+                .then_some((functor.as_str(), parameters, body))
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Formula {
+    Equation(Equation),
+    Conjunction(Vec<Self>),
+}
+
+impl Formula {
+    fn equations(self, equations: &mut Vec<Equation>) {
+        match self {
+            Self::Equation(equation) => equations.push(equation),
+            Self::Conjunction(formulas) => {
+                for formula in formulas {
+                    formula.equations(equations);
+                }
+            }
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize, usize)>,
+    cursor: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.cursor].0
+    }
+
+    fn error(&self, kind: TptpErrorKind) -> TptpError {
+        let (_, line, column) = self.tokens[self.cursor];
+        TptpError { line, column, kind }
+    }
+
+    fn unexpected(&self, expected: &'static str) -> TptpError {
+        self.error(TptpErrorKind::UnexpectedToken {
+            expected,
+            found: self.peek().to_string(),
+        })
+    }
+
+    fn unsupported(&self, construct: impl Display) -> TptpError {
+        self.error(TptpErrorKind::Unsupported(construct.to_string()))
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.peek().clone();
+        if token != Token::EndOfInput {
+            self.cursor += 1;
+        }
+        token
+    }
+
+    fn eat(&mut self, punctuation: &str) -> bool {
+        if matches!(self.peek(), Token::Punctuation(p) if *p == punctuation) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, punctuation: &'static str) -> Result<(), TptpError> {
+        if self.eat(punctuation) {
+            Ok(())
+        } else {
+            Err(self.unexpected(punctuation))
+        }
+    }
+
+    fn lower_word(&mut self, expected: &'static str) -> Result<String, TptpError> {
+        if let Token::LowerWord(word) = self.peek().clone() {
+            self.advance();
+            Ok(word)
+        } else {
+            Err(self.unexpected(expected))
+        }
+    }
+
+    fn term(&mut self) -> Result<RawTerm, TptpError> {
+        match self.peek().clone() {
+            Token::UpperWord(variable) => {
+                self.advance();
+                Ok(RawTerm::Variable(variable))
+            }
+            Token::LowerWord(functor) => {
+                self.advance();
+
+                let mut arguments = Vec::new();
+                if self.eat("(") {
+                    loop {
+                        arguments.push(self.term()?);
+                        if !self.eat(",") {
+                            break;
+                        }
+                    }
+                    self.expect(")")?;
+                }
+
+                Ok(RawTerm::Application(functor, arguments))
+            }
+            Token::Other(word) => Err(self.unsupported(format!("`{word}`"))),
+            _ => Err(self.unexpected("a term")),
+        }
+    }
+
+    fn atom(&mut self, negated: bool) -> Result<Formula, TptpError> {
+        let (_, line, column) = self.tokens[self.cursor];
+        let lhs = self.term()?;
+
+        let negated = if self.eat("=") {
+            negated
+        } else if self.eat("!=") {
+            !negated
+        } else {
+            return Err(self.unsupported("a predicate"));
+        };
+
+        let rhs = self.term()?;
+
+        Ok(Formula::Equation(Equation {
+            lhs,
+            rhs,
+            negated,
+            line,
+            column,
+        }))
+    }
+
+    fn unitary_formula(&mut self, negated: bool) -> Result<Formula, TptpError> {
+        if self.eat("(") {
+            let formula = self.formula(negated)?;
+            self.expect(")")?;
+            return Ok(formula);
+        }
+
+        if self.eat("~") {
+            return self.unitary_formula(!negated);
+        }
+
+        if self.eat("!") {
+            if negated {
+                return Err(self.unsupported("a negated quantifier"));
+            }
+
+            // the variables are universally quantified in the whole formula anyway
+            self.expect("[")?;
+            loop {
+                if !matches!(self.peek(), Token::UpperWord(_)) {
+                    return Err(self.unexpected("a variable"));
+                }
+                self.advance();
+                if !self.eat(",") {
+                    break;
+                }
+            }
+            self.expect("]")?;
+            self.expect(":")?;
+
+            return self.unitary_formula(false);
+        }
+
+        if self.eat("?") {
+            return Err(self.unsupported("an existential quantifier"));
+        }
+
+        self.atom(negated)
+    }
+
+    fn formula(&mut self, negated: bool) -> Result<Formula, TptpError> {
+        let first = self.unitary_formula(negated)?;
+
+        if !matches!(self.peek(), Token::Punctuation("&")) {
+            if let Token::Punctuation(
+                connective @ ("|" | "=>" | "<=" | "<=>" | "<~>" | "~|" | "~&"),
+            ) = self.peek()
+            {
+                return Err(self.unsupported(format!("the connective `{connective}`")));
+            }
+            return Ok(first);
+        }
+
+        if negated {
+            return Err(self.unsupported("a negated conjunction"));
+        }
+
+        let mut conjuncts = vec![first];
+        while self.eat("&") {
+            conjuncts.push(self.unitary_formula(false)?);
+        }
+
+        Ok(Formula::Conjunction(conjuncts))
+    }
+
+    fn clause(&mut self, negated: bool) -> Result<Formula, TptpError> {
+        let literal = if self.eat("(") {
+            let clause = self.clause(negated)?;
+            self.expect(")")?;
+            clause
+        } else if self.eat("~") {
+            self.clause(!negated)?
+        } else {
+            self.atom(negated)?
+        };
+
+        if matches!(self.peek(), Token::Punctuation("|")) {
+            return Err(self.unsupported("a non-unit clause"));
+        }
+
+        Ok(literal)
+    }
+
+    /// Skips the optional annotations of an annotated formula up to its closing parenthesis.
+    fn skip_annotations(&mut self) -> Result<(), TptpError> {
+        let mut depth = 0_usize;
+        loop {
+            match self.peek() {
+                Token::Punctuation("(" | "[") => depth += 1,
+                Token::Punctuation(")" | "]") if depth == 0 => return Ok(()),
+                Token::Punctuation(")" | "]") => depth -= 1,
+                Token::EndOfInput => return Err(self.unexpected("`)`")),
+                _ => {}
+            }
+            self.advance();
+        }
+    }
+
+    /// Parses an annotated formula into its role and equations.
+    fn annotated_formula(&mut self) -> Result<(String, Vec<Equation>), TptpError> {
+        let Token::LowerWord(language) = self.peek().clone() else {
+            return Err(self.unexpected("`fof` or `cnf`"));
+        };
+
+        if !matches!(language.as_str(), "fof" | "cnf") {
+            return Err(self.unsupported(format!("`{language}`")));
+        }
+        self.advance();
+
+        self.expect("(")?;
+        if !matches!(self.peek(), Token::LowerWord(_) | Token::Other(_)) {
+            return Err(self.unexpected("a name"));
+        }
+        self.advance();
+        self.expect(",")?;
+        let role = self.lower_word("a role")?;
+        self.expect(",")?;
+
+        let formula = if language == "fof" {
+            self.formula(false)?
+        } else {
+            self.clause(false)?
+        };
+
+        if self.eat(",") {
+            self.skip_annotations()?;
+        }
+        self.expect(")")?;
+        self.expect(".")?;
+
+        let mut equations = Vec::new();
+        formula.equations(&mut equations);
+
+        Ok((role, equations))
+    }
+}
+
+struct Importer {
+    definitions: HashMap<String, usize>,
+}
+
+impl Importer {
+    fn term(&self, term: &RawTerm) -> Term<String> {
+        match term {
+            RawTerm::Variable(variable) => Term::Literal(variable.clone()),
+            RawTerm::Application(functor, arguments) => {
+                let arguments = arguments.iter().map(|a| self.term(a)).collect::<Vec<_>>();
+
+                if self.definitions.get(functor) == Some(&arguments.len()) {
+                    Term::Normalizable(Normalizable {
+                        symbol: functor.clone(),
+                        arguments,
+                    })
+                } else if arguments.is_empty() {
+                    Term::Literal(functor.clone())
+                } else {
+                    Term::Function(Function {
+                        symbol: functor.clone(),
+                        arguments,
+                    })
+                }
+            }
+        }
+    }
+}
+
+/// Imports a TPTP problem in the ground and unit-equational subset of the `fof` and `cnf`
+/// languages.
+///
+/// The annotated formulas are imported as follows:
+///
+/// - Axioms (any role other than `conjecture` and `negated_conjecture`) must be equations or
+///   conjunctions of equations. Ground equations are added to the premise. A universally
+///   quantified equation of the form `f(X1, ..., Xn) = body`, where the `Xi` are distinct
+///   variables that are exactly the variables of `body` and `f` does not occur in `body`, adds a
+///   normalization of `f`. Every application of `f` with `n` arguments is then imported as a
+///   [`Term::Normalizable`].
+/// - A `conjecture` must be a single equation, whose variables are treated as constants.
+/// - A `negated_conjecture` must be a single ground disequation `s != t`.
+///
+/// Constants are imported as [`Term::Literal`] and other applications as [`Term::Function`].
+///
+/// # Errors
+///
+/// Returns [`TptpError`] if the problem is malformed or uses anything outside of this subset,
+/// such as `include`, predicates, disjunctions or existential quantifiers.
+pub fn parse_tptp(source: &str) -> Result<TptpProblem, TptpError> {
+    let mut parser = Parser {
+        tokens: tokenize(source)?,
+        cursor: 0,
+    };
+
+    let mut axioms = Vec::new();
+    let mut conjecture = None;
+
+    while parser.peek() != &Token::EndOfInput {
+        if parser.peek() == &Token::LowerWord("include".to_string()) {
+            return Err(parser.unsupported("`include`"));
+        }
+
+        let (role, mut equations) = parser.annotated_formula()?;
+
+        match role.as_str() {
+            "conjecture" | "negated_conjecture" => {
+                let Some(equation) = equations.pop() else {
+                    unreachable!("a formula has at least one equation")
+                };
+
+                if !equations.is_empty() {
+                    return Err(equation.error(TptpErrorKind::Unsupported(
+                        "a conjecture with more than one equation".to_string(),
+                    )));
+                }
+
+                let negated = role == "negated_conjecture";
+                if equation.negated != negated {
+                    return Err(equation.error(TptpErrorKind::Unsupported(format!(
+                        "a {} equation in a `{role}`",
+                        if equation.negated {
+                            "negated"
+                        } else {
+                            "positive"
+                        }
+                    ))));
+                }
+
+                if negated && !equation.is_ground() {
+                    return Err(equation.error(TptpErrorKind::Unsupported(
+                        "a non-ground `negated_conjecture`".to_string(),
+                    )));
+                }
+
+                if conjecture.replace(equation.clone()).is_some() {
+                    return Err(equation.error(TptpErrorKind::MultipleConjectures));
+                }
+            }
+            "axiom" | "hypothesis" | "definition" | "assumption" | "lemma" | "theorem"
+            | "corollary" | "plain" => {
+                for equation in equations {
+                    if equation.negated {
+                        return Err(equation.error(TptpErrorKind::Unsupported(
+                            "a negated equation in an axiom".to_string(),
+                        )));
+                    }
+                    axioms.push(equation);
+                }
+            }
+            role => return Err(parser.unsupported(format!("the role `{role}`"))),
+        }
+    }
+
+    let mut importer = Importer {
+        definitions: HashMap::new(),
+    };
+    let mut definitions = Vec::new();
+    let mut ground = Vec::new();
+
+    for equation in &axioms {
+        if equation.is_ground() {
+            ground.push(equation);
+            continue;
+        }
+
+        let (functor, parameters, body) = equation.as_definition().ok_or_else(|| {
+            equation.error(TptpErrorKind::Unsupported(
+                "a non-ground equation that is not a definition".to_string(),
+            ))
+        })?;
+
+        if importer
+            .definitions
+            .insert(functor.to_string(), parameters.len())
+            .is_some()
+        {
+            return Err(equation.error(TptpErrorKind::DuplicateDefinition(functor.to_string())));
+        }
+
+        definitions.push((equation, functor, parameters, body));
+    }
+
+    let mut premise = Premise::default();
+
+    for (equation, functor, parameters, body) in definitions {
+        premise
+            .insert_normalization(functor.to_string(), parameters, importer.term(body))
+            .map_err(|error| equation.error(TptpErrorKind::InvalidDefinition(error)))?;
+    }
+    for equation in ground {
+        premise.insert(importer.term(&equation.lhs), importer.term(&equation.rhs));
+    }
+
+    Ok(TptpProblem {
+        premise,
+        conjecture: conjecture
+            .map(|equation| (importer.term(&equation.lhs), importer.term(&equation.rhs))),
+    })
+}
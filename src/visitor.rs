@@ -3,32 +3,199 @@ use crate::{Function, Normalizable, Term};
 use std::fmt::Debug;
 use std::hash::Hash;
 
+/// The location of a term being visited relative to the term the traversal started from.
+#[derive(Debug)]
+pub struct Context<'a, Literal: Ord + Eq + Hash + Clone + Debug> {
+    /// The term whose argument list contains the visited term.
+    ///
+    /// `None` if the visited term is the root of the traversal.
+    pub parent: Option<&'a Term<Literal>>,
+
+    /// The index of the visited term in the argument list of its parent.
+    ///
+    /// `None` if the visited term is the root of the traversal.
+    pub index: Option<usize>,
+
+    /// The number of terms between the visited term and the root of the traversal.
+    pub depth: usize,
+}
+
+impl<'a, Literal: Ord + Eq + Hash + Clone + Debug> Context<'a, Literal> {
+    /// Creates a context for the root of a traversal.
+    #[must_use]
+    pub const fn root() -> Self {
+        Self {
+            parent: None,
+            index: None,
+            depth: 0,
+        }
+    }
+
+    /// Creates a context for the `index`-th argument of `parent`, where `self` is the context
+    /// of `parent`.
+    #[must_use]
+    pub const fn child(&self, parent: &'a Term<Literal>, index: usize) -> Self {
+        Self {
+            parent: Some(parent),
+            index: Some(index),
+            depth: self.depth + 1,
+        }
+    }
+}
+
+// implemented manually as deriving would require `Literal: Copy`
+#[allow(clippy::expl_impl_clone_on_copy)]
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Clone for Context<'_, Literal> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Literal: Ord + Eq + Hash + Clone + Debug> Copy for Context<'_, Literal> {}
+
 /// A visitor for terms,
 pub trait Visitor<Literal: Ord + Eq + Hash + Clone + Debug> {
-    /// Visits a term.
+    /// Visits a term before its arguments are visited.
+    ///
+    /// Returns `false` if visiting should be stopped.
+    fn visit(&mut self, term: &Term<Literal>) -> bool {
+        let _ = term;
+        true
+    }
+
+    /// Visits a term before its arguments are visited, with the location of the term.
+    ///
+    /// By default, this forwards to [`Visitor::visit`].
+    ///
+    /// Returns `false` if visiting should be stopped.
+    fn visit_pre(&mut self, term: &Term<Literal>, context: Context<'_, Literal>) -> bool {
+        let _ = context;
+        self.visit(term)
+    }
+
+    /// Visits a term after all of its arguments have been visited, with the location of the
+    /// term.
+    ///
+    /// Returns `false` if visiting should be stopped.
+    fn visit_post(&mut self, term: &Term<Literal>, context: Context<'_, Literal>) -> bool {
+        let _ = (term, context);
+        true
+    }
+}
+
+/// A visitor for terms that is allowed to rewrite the terms in place.
+pub trait VisitorMut<Literal: Ord + Eq + Hash + Clone + Debug> {
+    /// Visits a term before its arguments are visited.
+    ///
+    /// The arguments visited afterwards are the arguments of the term as left by this method.
+    ///
+    /// Returns `false` if visiting should be stopped.
+    fn visit_mut(&mut self, term: &mut Term<Literal>) -> bool {
+        let _ = term;
+        true
+    }
+
+    /// Visits a term after all of its arguments have been visited.
     ///
     /// Returns `false` if visiting should be stopped.
-    fn visit(&mut self, term: &Term<Literal>) -> bool;
+    fn visit_post_mut(&mut self, term: &mut Term<Literal>) -> bool {
+        let _ = term;
+        true
+    }
+}
+
+/// Rebuilds terms bottom-up.
+///
+/// Each method receives a term whose arguments have already been folded and returns the term
+/// to put in its place. The default implementations rebuild the term unchanged.
+pub trait Folder<Literal: Ord + Eq + Hash + Clone + Debug> {
+    /// Folds a literal term.
+    fn fold_literal(&mut self, literal: Literal) -> Term<Literal> {
+        Term::Literal(literal)
+    }
+
+    /// Folds a function term whose arguments have already been folded.
+    fn fold_function(&mut self, function: Function<Literal>) -> Term<Literal> {
+        Term::Function(function)
+    }
+
+    /// Folds a normalizable term whose arguments have already been folded.
+    fn fold_normalizable(&mut self, normalizable: Normalizable<Literal>) -> Term<Literal> {
+        Term::Normalizable(normalizable)
+    }
 }
 
 impl<Literal: Ord + Eq + Hash + Clone + Debug> Term<Literal> {
     /// Visits the term.
     pub fn visit<V: Visitor<Literal>>(&self, visitor: &mut V) -> bool {
-        if !visitor.visit(self) {
+        self.visit_with_context(visitor, Context::root())
+    }
+
+    fn visit_with_context<V: Visitor<Literal>>(
+        &self,
+        visitor: &mut V,
+        context: Context<'_, Literal>,
+    ) -> bool {
+        if !visitor.visit_pre(self, context) {
+            return false;
+        }
+
+        match self {
+            Self::Literal(_) => {}
+            Self::Function(Function { arguments, .. })
+            | Self::Normalizable(Normalizable { arguments, .. }) => {
+                for (index, argument) in arguments.iter().enumerate() {
+                    if !argument.visit_with_context(visitor, context.child(self, index)) {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        visitor.visit_post(self, context)
+    }
+
+    /// Visits the term mutably.
+    pub fn visit_mut<V: VisitorMut<Literal>>(&mut self, visitor: &mut V) -> bool {
+        if !visitor.visit_mut(self) {
             return false;
         }
 
         match self {
-            Self::Literal(_) => true,
+            Self::Literal(_) => {}
             Self::Function(Function { arguments, .. })
             | Self::Normalizable(Normalizable { arguments, .. }) => {
                 for argument in arguments {
-                    if !argument.visit(visitor) {
+                    if !argument.visit_mut(visitor) {
                         return false;
                     }
                 }
+            }
+        }
+
+        visitor.visit_post_mut(self)
+    }
+
+    /// Folds the term bottom-up, see [`Folder`].
+    #[must_use]
+    pub fn fold<F: Folder<Literal>>(self, folder: &mut F) -> Self {
+        match self {
+            Self::Literal(literal) => folder.fold_literal(literal),
+            Self::Function(Function { symbol, arguments }) => {
+                let arguments = arguments
+                    .into_iter()
+                    .map(|argument| argument.fold(folder))
+                    .collect();
+
+                folder.fold_function(Function { symbol, arguments })
+            }
+            Self::Normalizable(Normalizable { symbol, arguments }) => {
+                let arguments = arguments
+                    .into_iter()
+                    .map(|argument| argument.fold(folder))
+                    .collect();
 
-                true
+                folder.fold_normalizable(Normalizable { symbol, arguments })
             }
         }
     }
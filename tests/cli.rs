@@ -0,0 +1,87 @@
+//! Tests of the `fol-equality` command-line tool.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static FILES: AtomicUsize = AtomicUsize::new(0);
+
+fn run(arguments: &[&str], premise: &str, queries: &str) -> Output {
+    let path = std::env::temp_dir().join(format!(
+        "fol-equality-{}-{}.txt",
+        std::process::id(),
+        FILES.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::write(&path, premise).unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_fol-equality"))
+        .args(arguments)
+        .arg(&path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(queries.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+    output
+}
+
+/// Removes the timings so that the output can be compared.
+fn answers(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| line.split(" (").next().unwrap())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn batch_queries() {
+    let output = run(
+        &["--explain"],
+        "a = b;\nf(b) = c;\nd = e;\nnorm N(x) := f(x);\n",
+        "// comment\n? f(a) = c\n\n? @N(a) = c\n? a = d\n",
+    );
+
+    assert!(output.status.success());
+    assert_eq!(
+        answers(&output),
+        "yes\n  because a = b\n  because c = f(b)\nyes\n  because a = b\n  because c = f(b)\nno"
+    );
+}
+
+#[test]
+fn explanation_with_axioms() {
+    let output = run(
+        &["--explain"],
+        "axioms f: commutative;\na = b;\nc = d;\nx = y;\n",
+        "? f(a, c) = f(d, b)\n",
+    );
+
+    assert!(output.status.success());
+    assert_eq!(answers(&output), "yes\n  because a = b\n  because c = d");
+}
+
+#[test]
+fn errors() {
+    let output = run(&[], "a = b;", "? a = b\na = b\n? f( = a\n");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(answers(&output), "yes");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "2: a query must start with `?`\n3: left-hand side: 1:5: expected a term, found end of \
+         input\n"
+    );
+
+    let output = run(&[], "a = ;", "");
+    assert_eq!(output.status.code(), Some(2));
+}